@@ -0,0 +1,80 @@
+//! Run with and without `--features simd` to compare the scalar and the word-at-a-time scanning.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use uapi_version::{strverscmp, Version};
+
+/// Build a shuffled corpus of kernel and systemd style versions.
+fn corpus() -> Vec<Version> {
+    let mut versions = Vec::new();
+    for major in 4..7 {
+        for minor in 0..30 {
+            for patch in 0..30 {
+                versions.push(format!(
+                    "{major}.{minor}.{patch}-{}.fc40.x86_64",
+                    patch % 3 + 100
+                ));
+            }
+            versions.push(format!("{major}.{minor}~rc{}", minor % 8 + 1));
+        }
+    }
+    for release in 240..258 {
+        for minor in 0..10 {
+            versions.push(format!("{release}.{minor}-1.el9"));
+            versions.push(format!("{release}~rc{minor}"));
+            versions.push(format!("{release}^20240{}15git{minor:03x}", minor % 9 + 1));
+        }
+    }
+
+    // Shuffle deterministically with a simple linear congruential generator.
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    for i in (1..versions.len()).rev() {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        let j = usize::try_from(state >> 33).unwrap_or_default() % (i + 1);
+        versions.swap(i, j);
+    }
+    versions.into_iter().map(Version::from).collect()
+}
+
+fn sort_corpus(c: &mut Criterion) {
+    let versions = corpus();
+    c.bench_function("sort corpus", |b| {
+        b.iter_batched_ref(
+            || versions.clone(),
+            |versions| versions.sort(),
+            BatchSize::SmallInput,
+        );
+    });
+}
+
+fn long_segments(c: &mut Criterion) {
+    let digits = "1234567890".repeat(100);
+    let left_numeric = format!("1.{digits}0");
+    let right_numeric = format!("1.{digits}1");
+
+    let letters = "abcdefghijklmnopqrstuvwxyz".repeat(40);
+    let left_alpha = format!("1.{letters}a");
+    let right_alpha = format!("1.{letters}b");
+
+    let zeros = "0".repeat(1000);
+    let left_zeros = format!("1.{zeros}1");
+    let right_zeros = format!("1.{zeros}2");
+
+    let mut group = c.benchmark_group("long segments");
+    group.bench_function("numeric", |b| {
+        b.iter(|| strverscmp(black_box(&left_numeric), black_box(&right_numeric)));
+    });
+    group.bench_function("alpha", |b| {
+        b.iter(|| strverscmp(black_box(&left_alpha), black_box(&right_alpha)));
+    });
+    group.bench_function("leading zeros", |b| {
+        b.iter(|| strverscmp(black_box(&left_zeros), black_box(&right_zeros)));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, sort_corpus, long_segments);
+criterion_main!(benches);
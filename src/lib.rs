@@ -45,13 +45,57 @@
 //!
 //! assert_eq!(strverscmp("124", "123"), Ordering::Greater)
 //! ```
+//!
+//! # Features
+//!
+//! - `simd`: Scan runs of digits and letters eight bytes at a time. This speeds up comparing
+//!   versions with long segments and doesn't change the result.
+//! - `std`: Provide `VersionMap`, which depends on the standard library's `HashMap`, and
+//!   `Version::from_os_str`.
+//! - `serde`: Implement `Serialize` and `Deserialize` for [`Version`] as a plain string. The
+//!   `structured` module additionally provides a representation with separate fields. The units
+//!   returned by `Version::comparison_vector` can be serialized as well.
 #![no_std]
+#![forbid(unsafe_code)]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+mod map;
+mod normalized;
+mod parsed;
+mod req;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "simd")]
+mod simd;
+mod symbolic;
+mod token;
+mod versions;
+mod writer;
 
 use alloc::fmt;
-use alloc::string::String;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use core::cmp::Ordering;
+use core::ops::Range;
+
+#[cfg(feature = "serde")]
+pub use crate::serde::structured;
+#[cfg(feature = "std")]
+pub use map::VersionMap;
+pub use normalized::NormalizedVersion;
+pub use parsed::ParsedVersion;
+pub use req::{latest_matching, Comparator, ParseReqError, ReqOp, VersionReq};
+pub use symbolic::SymbolicVersion;
+pub use token::{Tokens, VersionToken, VersionTokenKind};
+pub use versions::{analyze, ceil, floor, VersionInfo, Versions};
+pub use writer::VersionWriter;
+
+use token::{RawToken, Scanner};
 
 /// The `Version` type.
 ///
@@ -70,207 +114,2943 @@ use core::cmp::Ordering;
 /// // `a` is smaller (i.e. older) than `b`.
 /// assert_eq!(a.cmp(&b), Ordering::Less)
 /// ```
+///
+/// `==` compares the underlying strings, while [`Ord`] compares them according to the
+/// specification. Two versions can thus be unequal and still compare as [`Ordering::Equal`]. Use
+/// [`Version::is_equivalent`] to check for the latter.
+///
+/// ```
+/// use uapi_version::Version;
+///
+/// let a = Version::from("1_");
+/// let b = Version::from("1");
+///
+/// assert_ne!(a, b);
+/// assert!(a.is_equivalent(&b));
+/// ```
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct Version(String);
 
 impl Version {
+    /// Build a version from a string with surrounding ASCII whitespace removed.
+    ///
+    /// Whitespace inside the string is kept as is. Like any other invalid character, it is ignored
+    /// during comparison.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert_eq!(Version::from_trimmed("  1.0\n").as_str(), "1.0");
+    /// ```
+    #[must_use]
+    pub fn from_trimmed(s: &str) -> Self {
+        Self(s.trim_ascii().into())
+    }
+
+    /// Build a version from the output of `git describe`, e.g. `v1.2.3-5-g1a2b3c`.
+    ///
+    /// A leading `v` is stripped from the tag. The number of commits since the tag becomes a `^`
+    /// patch part, so `v1.2.3-5-g1a2b3c` becomes `1.2.3^5`, which is newer than `1.2.3` and older
+    /// than `1.2.4`. With zero commits, as printed by `git describe --long`, the result is just the
+    /// tag. The abbreviated commit hash can't be kept without affecting the comparison and is
+    /// dropped. Output without the `-N-g<hash>` suffix is taken as a plain tag. Returns `None` if
+    /// the tag is empty.
+    ///
+    /// The `-dirty` suffix that `git describe --dirty` appends for uncommitted changes is
+    /// metadata like the hash and is dropped as well, so a dirty tree has the version of the
+    /// commit it is based on. Custom marks given as `--dirty=<mark>` aren't recognized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let version = Version::from_git_describe("v1.2.3-5-g1a2b3c");
+    ///
+    /// assert_eq!(version, Some(Version::from("1.2.3^5")));
+    /// assert_eq!(Version::from_git_describe("v1.2.3-5-g1a2b3c-dirty"), version);
+    /// ```
+    #[must_use]
+    pub fn from_git_describe(s: &str) -> Option<Self> {
+        let s = s.strip_prefix('v').unwrap_or(s);
+        let s = s.strip_suffix("-dirty").unwrap_or(s);
+        let (tag, commits) = s
+            .rsplit_once("-g")
+            .and_then(|(rest, hash)| {
+                let (tag, commits) = rest.rsplit_once('-')?;
+                let is_describe = !hash.is_empty()
+                    && hash.bytes().all(|b| b.is_ascii_hexdigit())
+                    && !commits.is_empty()
+                    && commits.bytes().all(|b| b.is_ascii_digit());
+                is_describe.then_some((tag, Some(commits)))
+            })
+            .unwrap_or((s, None));
+        if tag.is_empty() {
+            return None;
+        }
+        Some(Self(match commits {
+            Some(commits) if commits.bytes().any(|b| b != b'0') => format!("{tag}^{commits}"),
+            _ => tag.into(),
+        }))
+    }
+
+    /// Build a version from a Linux kernel release as printed by `uname -r`.
+    ///
+    /// Kernel release candidates are marked with `-rcN`, which would sort after the final release.
+    /// If the first `-` of `s` starts such a marker, it's replaced by a `~` so that `6.1.0-rc2`
+    /// becomes `6.1.0~rc2` and sorts before `6.1.0`. Everything else is kept as is. The suffix that
+    /// distributions append, e.g. `arch1-1` in `6.1.0-rc2-arch1-1`, thus becomes the
+    /// [release](Version::release) part of the version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let version = Version::from_kver("6.1.0-rc2-arch1-1");
+    ///
+    /// assert_eq!(version.as_str(), "6.1.0~rc2-arch1-1");
+    /// assert_eq!(version.release(), Some("arch1-1"));
+    /// assert!(version < Version::from_kver("6.1.0-arch1-1"));
+    /// ```
+    #[must_use]
+    pub fn from_kver(s: &str) -> Self {
+        let Some((upstream, rest)) = s.split_once('-') else {
+            return Self(s.into());
+        };
+        let is_rc = rest.strip_prefix("rc").is_some_and(|rest| {
+            let digits = leading_digits(rest);
+            !digits.is_empty() && matches!(rest[digits.len()..].chars().next(), None | Some('-'))
+        });
+        if is_rc {
+            Self(format!("{upstream}~{rest}"))
+        } else {
+            Self(s.into())
+        }
+    }
+
+    /// Build a version from each `delimiter`-separated piece of `s`.
+    ///
+    /// Pieces are trimmed like in [`Version::from_trimmed`] and empty pieces are skipped, so a
+    /// trailing delimiter doesn't produce an empty version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let versions = Version::parse_list("1.0, 2.0, 3.0,", ',');
+    ///
+    /// assert_eq!(versions, ["1.0", "2.0", "3.0"].map(Version::from));
+    /// ```
+    #[must_use]
+    pub fn parse_list(s: &str, delimiter: char) -> Vec<Version> {
+        s.split(delimiter)
+            .map(str::trim_ascii)
+            .filter(|piece| !piece.is_empty())
+            .map(Version::from)
+            .collect()
+    }
+
+    /// Extract a version from a file name, e.g. of a boot loader entry.
+    ///
+    /// The version starts at the first digit and extends over the following valid version
+    /// characters. A trailing `.`-separated suffix made up only of letters is considered to be the
+    /// file extension and dropped. Returns `None` if the name contains no digit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert_eq!(
+    ///     Version::from_filename("linux-6.1.0-rc2.conf"),
+    ///     Some(Version::from("6.1.0-rc2"))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_filename(name: &str) -> Option<Self> {
+        let start = name.find(|c: char| c.is_ascii_digit())?;
+        let rest = &name[start..];
+        let mut version = &rest[..rest
+            .find(|c| !is_valid_version_char(c))
+            .unwrap_or(rest.len())];
+        if let Some((head, extension)) = version.rsplit_once('.') {
+            if !extension.is_empty() && extension.bytes().all(|b| b.is_ascii_alphabetic()) {
+                version = head;
+            }
+        }
+        Some(Self(version.into()))
+    }
+
+    /// Build a version from an [`OsStr`](std::ffi::OsStr), e.g. a file name.
+    ///
+    /// Returns `None` if `s` is not valid UTF-8. A lossy conversion isn't done because the
+    /// replacement character is invalid in a version and would be silently ignored, so different
+    /// names could end up comparing as equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use uapi_version::Version;
+    ///
+    /// assert_eq!(Version::from_os_str(OsStr::new("1.0")), Some(Version::from("1.0")));
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn from_os_str(s: &std::ffi::OsStr) -> Option<Self> {
+        s.to_str().map(Self::from)
+    }
+
     #[must_use]
     pub fn as_str(&self) -> &str {
         &self.0
     }
 
+    /// Return the underlying bytes of the version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert_eq!(Version::from("1.0").as_bytes(), b"1.0");
+    /// ```
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+
     #[must_use]
     pub fn into_string(self) -> String {
         self.0
     }
-}
 
-impl From<&str> for Version {
-    fn from(s: &str) -> Self {
-        Self(s.into())
+    /// Check whether two versions compare as [`Ordering::Equal`].
+    ///
+    /// Unlike `==`, this doesn't compare the underlying strings but follows the specification.
+    #[must_use]
+    pub fn is_equivalent(&self, other: &Version) -> bool {
+        self.cmp(other) == Ordering::Equal
     }
-}
 
-impl From<String> for Version {
-    fn from(s: String) -> Self {
-        Self(s)
+    /// Check whether the version lies between `lo` and `hi`.
+    ///
+    /// The two elements of `inclusive` decide whether `lo` and `hi` respectively are part of the
+    /// range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let lo = Version::from("1.0");
+    /// let hi = Version::from("2.0");
+    ///
+    /// assert!(Version::from("1.5").in_range(&lo, &hi, (true, false)));
+    /// assert!(!Version::from("2.0").in_range(&lo, &hi, (true, false)));
+    /// ```
+    #[must_use]
+    pub fn in_range(&self, lo: &Version, hi: &Version, inclusive: (bool, bool)) -> bool {
+        let above_lo = match self.cmp(lo) {
+            Ordering::Greater => true,
+            Ordering::Equal => inclusive.0,
+            Ordering::Less => false,
+        };
+        let below_hi = match self.cmp(hi) {
+            Ordering::Less => true,
+            Ordering::Equal => inclusive.1,
+            Ordering::Greater => false,
+        };
+        above_lo && below_hi
     }
-}
 
-impl From<&String> for Version {
-    fn from(s: &String) -> Self {
-        Self(s.into())
+    /// Return the newer of two versions.
+    ///
+    /// If both compare as equal, `self` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let newer = Version::from("1.0").pick_newer(Version::from("1.0~rc1"));
+    ///
+    /// assert_eq!(newer, Version::from("1.0"));
+    /// ```
+    #[must_use]
+    pub fn pick_newer(self, other: Version) -> Version {
+        if other > self {
+            other
+        } else {
+            self
+        }
     }
-}
 
-impl fmt::Display for Version {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.0)
+    /// Return the older of two versions.
+    ///
+    /// If both compare as equal, `self` is returned.
+    #[must_use]
+    pub fn pick_older(self, other: Version) -> Version {
+        if other < self {
+            other
+        } else {
+            self
+        }
     }
-}
 
-impl PartialOrd for Version {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+    /// Return the first three numbers of the version as a tuple.
+    ///
+    /// The numbers are taken from the leading run of `.`-separated numbers, e.g. `1` and `2` for
+    /// `1.2rc1-3`. Missing numbers default to `0` and numbers that don't fit into a `u64` saturate.
+    ///
+    /// This is a lossy convenience view. It ignores everything but the leading numbers, so unlike
+    /// [`strverscmp`], it doesn't tell apart `1.2~rc1`, `1.2` and `1.2-3`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert_eq!(Version::from("1.2").version_tuple(), (1, 2, 0));
+    /// ```
+    #[must_use]
+    pub fn version_tuple(&self) -> (u64, u64, u64) {
+        let mut numbers = numeric_core(&self.0).map(saturating_parse);
+        (
+            numbers.next().unwrap_or_default(),
+            numbers.next().unwrap_or_default(),
+            numbers.next().unwrap_or_default(),
+        )
     }
-}
 
-impl Ord for Version {
-    fn cmp(&self, other: &Self) -> Ordering {
-        strverscmp(&self.0, &other.0)
+    /// Build a requirement that compares against the version with `op`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::{ReqOp, Version};
+    ///
+    /// let req = Version::from("1.2.3").as_req(ReqOp::GreaterEq);
+    ///
+    /// assert_eq!(req.to_string(), ">=1.2.3");
+    /// assert!(req.matches(&Version::from("1.2.4")));
+    /// ```
+    #[must_use]
+    pub fn as_req(&self, op: ReqOp) -> VersionReq {
+        VersionReq::from(Comparator {
+            op,
+            version: self.clone(),
+        })
     }
-}
 
-/// Compare two version strings.
-///
-/// # Examples
-///
-/// ```
-/// use std::cmp::Ordering;
-///
-/// use uapi_version::strverscmp;
-///
-/// assert_eq!(strverscmp("1.0.0", "2.0.0"), Ordering::Less)
-/// ```
-#[must_use]
-#[allow(clippy::too_many_lines)]
-pub fn strverscmp(a: &str, b: &str) -> Ordering {
-    let mut left_iter = a.chars().peekable();
-    let mut right_iter = b.chars().peekable();
+    /// Check whether the version is a later revision of the same upstream version as `base`.
+    ///
+    /// The [upstream](Version::upstream) parts have to compare as equal and the
+    /// [release](Version::release) part of the version has to be newer than that of `base`. A
+    /// missing release part counts as empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let base = Version::from("1.0-5");
+    ///
+    /// assert!(Version::from("1.0-7").is_revision_after(&base));
+    /// assert!(!Version::from("1.1-7").is_revision_after(&base));
+    /// ```
+    #[must_use]
+    pub fn is_revision_after(&self, base: &Version) -> bool {
+        strverscmp(self.upstream(), base.upstream()) == Ordering::Equal
+            && strverscmp(
+                self.release().unwrap_or_default(),
+                base.release().unwrap_or_default(),
+            ) == Ordering::Greater
+    }
 
-    loop {
-        let mut left = left_iter.next();
-        let mut right = right_iter.next();
+    /// Check whether the version is the same or a newer patch of the same upstream version as
+    /// `base`.
+    ///
+    /// The [upstream](Version::upstream) parts have to compare as equal and the
+    /// [patch](Version::patch) part of the version must not be older than that of `base`. A
+    /// version without a `^` patch part counts as having an empty one, which is older than any
+    /// patch. So `1.0^1` is a patch update of `1.0`, but not the other way round. Release parts are
+    /// ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let base = Version::from("123^aa1-99.99");
+    ///
+    /// assert!(Version::from("123^aa2-67.89").is_patch_update_of(&base));
+    /// assert!(!Version::from("124^aa2").is_patch_update_of(&base));
+    /// ```
+    #[must_use]
+    pub fn is_patch_update_of(&self, base: &Version) -> bool {
+        strverscmp(self.upstream(), base.upstream()) == Ordering::Equal
+            && strverscmp(
+                self.patch().unwrap_or_default(),
+                base.patch().unwrap_or_default(),
+            ) != Ordering::Less
+    }
 
-        // Step 1: Skip invalid chars
-        while left.is_some() && !left.is_some_and(is_valid_version_char) {
-            left = left_iter.next();
-        }
-        while right.is_some() && !right.is_some_and(is_valid_version_char) {
-            right = right_iter.next();
+    /// Check whether both versions have the same major version.
+    ///
+    /// The major version is the number the version starts with. Versions that don't start with a
+    /// number aren't compatible with anything.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let version = Version::from("2.5");
+    ///
+    /// assert!(version.is_compatible_with(&Version::from("2.1")));
+    /// assert!(!version.is_compatible_with(&Version::from("3.0")));
+    /// ```
+    #[must_use]
+    pub fn is_compatible_with(&self, other: &Version) -> bool {
+        match (numeric_core(&self.0).next(), numeric_core(&other.0).next()) {
+            (Some(major), Some(other_major)) => strverscmp(major, other_major) == Ordering::Equal,
+            _ => false,
         }
+    }
 
-        // Step 2: Handle '~'
-        if left.is_some_and(|c| c == '~') || right.is_some_and(|c| c == '~') {
-            let ordering = compare_special_char('~', left, right);
-            if ordering != Ordering::Equal {
-                return ordering;
+    /// Return the leading `.`-separated numbers of the version as a new version.
+    ///
+    /// Each component contributes its leading run of digits, and everything from the first
+    /// component that doesn't consist only of digits onwards is dropped. This is lossy and changes
+    /// the ordering: `1.2~rc1` is older than `1.2-5`, but both become `1.2`. A version that
+    /// doesn't start with a number becomes the empty version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert_eq!(Version::from("1.2rc1-3").numeric_only(), Version::from("1.2"));
+    /// assert_eq!(Version::from("abc").numeric_only(), Version::from(""));
+    /// ```
+    #[must_use]
+    pub fn numeric_only(&self) -> Version {
+        let mut s = String::with_capacity(self.0.len());
+        for (i, digits) in numeric_core(&self.0).enumerate() {
+            if i > 0 {
+                s.push('.');
             }
+            s.push_str(digits);
         }
+        Self(s)
+    }
 
-        // Step 3: Handle empty
-        if left.is_none() || right.is_none() {
-            return left.cmp(&right);
+    /// Return the numeric skeleton of the version.
+    ///
+    /// The skeleton consists of the leading numeric run of each `.`-separated component of the
+    /// upstream part of the version, i.e. the part before the first `~`, `-` or `^`. Alphabetic
+    /// parts are dropped. This is useful as a grouping key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert_eq!(Version::from("1.2rc1").numeric_skeleton(), "1.2");
+    /// assert_eq!(Version::from("1.0~rc1-5").numeric_skeleton(), "1.0");
+    /// ```
+    #[must_use]
+    pub fn numeric_skeleton(&self) -> String {
+        let upstream = self.0.split(['~', '-', '^']).next().unwrap_or_default();
+        let mut skeleton = String::new();
+        for component in upstream.split('.') {
+            let digits =
+                leading_digits(component.trim_start_matches(|c| !is_valid_version_char(c)));
+            if digits.is_empty() {
+                continue;
+            }
+            if !skeleton.is_empty() {
+                skeleton.push('.');
+            }
+            skeleton.push_str(digits);
         }
+        skeleton
+    }
 
-        // Step 4: Handle '-'
-        if left.is_some_and(|c| c == '-') || right.is_some_and(|c| c == '-') {
-            let ordering = compare_special_char('-', left, right);
-            if ordering != Ordering::Equal {
-                return ordering;
+    /// Replace each run of the same separator with a single one.
+    ///
+    /// This changes how the version compares, e.g. `1..2` is older than `1.2`. It's intended for
+    /// cleaning up versions from sloppy sources before storing them, not for comparing versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert_eq!(Version::from("1..2~~rc1").collapse_separators(), Version::from("1.2~rc1"));
+    /// assert_eq!(Version::from("1.-.2").collapse_separators(), Version::from("1.-.2"));
+    /// ```
+    #[must_use]
+    pub fn collapse_separators(&self) -> Version {
+        let mut s = String::with_capacity(self.0.len());
+        for c in self.0.chars() {
+            if !(matches!(c, '~' | '-' | '^' | '.') && s.ends_with(c)) {
+                s.push(c);
             }
         }
+        Self(s)
+    }
 
-        // Step 5: Handle '^'
-        if left.is_some_and(|c| c == '^') || right.is_some_and(|c| c == '^') {
-            let ordering = compare_special_char('^', left, right);
-            if ordering != Ordering::Equal {
-                return ordering;
-            }
+    /// Append `.0` components until the version has at least `n` `.`-separated components.
+    ///
+    /// Versions whose last component isn't purely numerical, like `1.0~rc1`, are returned
+    /// unchanged. This changes how the version compares: `1.2.0` is newer than `1.2`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert_eq!(Version::from("1.2").pad_to_components(3), Version::from("1.2.0"));
+    /// assert_eq!(Version::from("1.2~rc1").pad_to_components(3), Version::from("1.2~rc1"));
+    /// ```
+    #[must_use]
+    pub fn pad_to_components(&self, n: usize) -> Version {
+        let count = self.component_count();
+        let is_numeric = self
+            .split_components()
+            .next_back()
+            .is_some_and(|last| !last.is_empty() && last.bytes().all(|b| b.is_ascii_digit()));
+        if count >= n || !is_numeric {
+            return self.clone();
         }
+        let mut s = self.0.clone();
+        for _ in count..n {
+            s.push_str(".0");
+        }
+        Self(s)
+    }
 
-        // Step 6: Handle '.'
-        if left.is_some_and(|c| c == '.') || right.is_some_and(|c| c == '.') {
-            let ordering = compare_special_char('.', left, right);
-            if ordering != Ordering::Equal {
-                return ordering;
+    /// Remove trailing `.`-separated components that are empty or zero, e.g. for display.
+    ///
+    /// The first component is always kept. This changes how the version compares: `1.2.0.0` is
+    /// newer than `1.2`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert_eq!(Version::from("1.2.0.0").shorten(), Version::from("1.2"));
+    /// assert_eq!(Version::from("1.0~rc1").shorten(), Version::from("1.0~rc1"));
+    /// ```
+    #[must_use]
+    pub fn shorten(&self) -> Version {
+        let mut s = self.0.as_str();
+        while let Some((head, last)) = s.rsplit_once('.') {
+            if !last.bytes().all(|b| b == b'0') {
+                break;
             }
+            s = head;
         }
+        Self(s.into())
+    }
 
-        // Step 7: Handle numerical prefix
-        if left.is_some_and(|c| c.is_ascii_digit()) || right.is_some_and(|c| c.is_ascii_digit()) {
-            // Skip leading '0's
-            while left.is_some_and(|c| c == '0') {
-                if !left_iter.peek().is_some_and(|c| c == &'0') {
-                    break;
-                }
-                left = left_iter.next();
-            }
-            while right.is_some_and(|c| c == '0') {
-                if !right_iter.peek().is_some_and(|c| c == &'0') {
-                    break;
-                }
-                right = right_iter.next();
+    /// Remove leading zeros from each run of digits, keeping a single `0` for runs of only zeros.
+    ///
+    /// Everything else is kept as is and the result compares as equal to the original.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert_eq!(Version::from("001.00-a07").strip_leading_zeros(), Version::from("1.0-a7"));
+    /// ```
+    #[must_use]
+    pub fn strip_leading_zeros(&self) -> Version {
+        let mut s = String::with_capacity(self.0.len());
+        let mut chars = self.0.chars().peekable();
+        let mut run_start = true;
+        while let Some(c) = chars.next() {
+            if c == '0' && run_start && chars.peek().is_some_and(char::is_ascii_digit) {
+                continue;
             }
+            run_start = !c.is_ascii_digit();
+            s.push(c);
+        }
+        Self(s)
+    }
 
-            let mut left_digit_prefix = String::new();
-            while left.is_some_and(|c| c.is_ascii_digit()) {
-                if let Some(char) = left {
-                    left_digit_prefix.push(char);
-                }
-                if !left_iter.peek().is_some_and(char::is_ascii_digit) {
-                    break;
-                }
-                left = left_iter.next();
-            }
+    /// Remove a trailing `.`-separated component that is one of `known_arches`.
+    ///
+    /// [`COMMON_ARCHES`] lists the usual architecture names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::{Version, COMMON_ARCHES};
+    ///
+    /// let version = Version::from("1.0-5.x86_64");
+    ///
+    /// assert_eq!(version.strip_arch_suffix(COMMON_ARCHES), Version::from("1.0-5"));
+    /// ```
+    #[must_use]
+    pub fn strip_arch_suffix(&self, known_arches: &[&str]) -> Version {
+        match self.0.rsplit_once('.') {
+            Some((head, arch)) if known_arches.contains(&arch) => Self(head.into()),
+            _ => self.clone(),
+        }
+    }
 
-            let mut right_digit_prefix = String::new();
-            while right.is_some_and(|c| c.is_ascii_digit()) {
-                if let Some(char) = right {
-                    right_digit_prefix.push(char);
-                }
-                if !right_iter.peek().is_some_and(char::is_ascii_digit) {
-                    break;
-                }
-                right = right_iter.next();
+    /// Remove the pre-release part from the version.
+    ///
+    /// The pre-release part starts at the first `~` in the upstream part and extends to the next
+    /// `^` or `-` or to the end of the version. The result is the release the pre-release leads up
+    /// to. A version without a pre-release part is returned unchanged.
+    ///
+    /// A `~` after the first `^` or `-` isn't a pre-release of the upstream version, but of the
+    /// patch or release part it is in. `1.0^2~rc1` leads up to the patch `1.0^2` and is thus
+    /// returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert_eq!(Version::from("1.0.0~rc1").strip_prerelease(), Version::from("1.0.0"));
+    /// assert_eq!(Version::from("1.0~rc1-5").strip_prerelease(), Version::from("1.0-5"));
+    /// assert_eq!(Version::from("1.0^2~rc1").strip_prerelease(), Version::from("1.0^2~rc1"));
+    /// ```
+    #[must_use]
+    pub fn strip_prerelease(&self) -> Version {
+        match self.prerelease_span() {
+            Some(span) => {
+                let mut s = self.0.clone();
+                s.replace_range(span, "");
+                Self(s)
             }
+            None => self.clone(),
+        }
+    }
 
-            if left_digit_prefix.len() != right_digit_prefix.len() {
-                return left_digit_prefix.len().cmp(&right_digit_prefix.len());
-            }
+    /// Return a copy of the version with its pre-release part set to `~tag`.
+    ///
+    /// The pre-release part is placed right after the upstream part, before any `^` patch or `-`
+    /// release part, and replaces an existing one. Returns `None` if `tag` is empty or contains a
+    /// `-`, `^` or any character that isn't valid in a version. Use
+    /// [`Version::clear_prerelease`] to remove the pre-release part again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let version = Version::from("1.0-5");
+    /// let prerelease = version.with_prerelease("rc1");
+    ///
+    /// assert_eq!(prerelease, Some(Version::from("1.0~rc1-5")));
+    /// assert!(prerelease.is_some_and(|v| v < version));
+    /// ```
+    #[must_use]
+    pub fn with_prerelease(&self, tag: &str) -> Option<Version> {
+        if tag.is_empty()
+            || !tag
+                .chars()
+                .all(|c| is_valid_version_char(c) && !matches!(c, '-' | '^'))
+        {
+            return None;
+        }
+        let span = self.prerelease_span().unwrap_or_else(|| {
+            let end = self.upstream().len();
+            end..end
+        });
+        let mut s = self.0.clone();
+        s.replace_range(span, &format!("~{tag}"));
+        Some(Self(s))
+    }
 
-            let ordering = left_digit_prefix.cmp(&right_digit_prefix);
-            if ordering != Ordering::Equal {
-                return ordering;
-            }
-        // Step 8: Handle alphabetical prefix
-        } else {
-            let mut left_alpha_prefix = String::new();
-            while left.is_some_and(|c| c.is_ascii_alphabetic()) {
-                if let Some(char) = left {
-                    left_alpha_prefix.push(char);
-                }
-                if !left_iter.peek().is_some_and(char::is_ascii_alphabetic) {
-                    break;
-                }
-                left = left_iter.next();
-            }
+    /// Return a copy of the version without its pre-release part.
+    ///
+    /// This is the counterpart of [`Version::with_prerelease`] and the same as
+    /// [`Version::strip_prerelease`]. Like there, a `~` after a `^` or `-` is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let version = Version::from("1.0~rc1^2-5");
+    ///
+    /// assert_eq!(version.clear_prerelease(), Version::from("1.0^2-5"));
+    /// assert_eq!(Version::from("1.0-5").clear_prerelease(), Version::from("1.0-5"));
+    /// ```
+    #[must_use]
+    pub fn clear_prerelease(&self) -> Version {
+        self.strip_prerelease()
+    }
 
-            let mut right_alpha_prefix = String::new();
-            while right.is_some_and(|c| c.is_ascii_alphabetic()) {
-                if let Some(char) = right {
-                    right_alpha_prefix.push(char);
-                }
-                if !right_iter.peek().is_some_and(char::is_ascii_alphabetic) {
-                    break;
-                }
-                right = right_iter.next();
+    /// Return whether the version has a pre-release part.
+    ///
+    /// See [`Version::strip_prerelease`] for what counts as the pre-release part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert!(Version::from("1.0~rc1").is_prerelease());
+    /// assert!(!Version::from("1.0-1~rc1").is_prerelease());
+    /// ```
+    #[must_use]
+    pub fn is_prerelease(&self) -> bool {
+        self.prerelease_span().is_some()
+    }
+
+    /// Check whether the version is a zero placeholder like `0` or `0.0.0`.
+    ///
+    /// This is the case if the version consists of at least one number, all numbers are zero and
+    /// the only separators are `.`. Letters and any of `~`, `-` and `^` make the version non-zero.
+    /// Invalid characters are ignored like during comparison, so `0_` and `_0_` are zero as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert!(Version::from("0.00.0").is_zero());
+    /// assert!(!Version::from("0.1").is_zero());
+    /// assert!(!Version::from("0~rc0").is_zero());
+    /// ```
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.tokens().all(|t| match t.kind() {
+            VersionTokenKind::Numeric => t.as_str().bytes().all(|b| b == b'0'),
+            VersionTokenKind::Dot => true,
+            _ => false,
+        }) && self.tokens().any(|t| t.kind() == VersionTokenKind::Numeric)
+    }
+
+    /// Return the first run of letters of the pre-release part, e.g. `rc` for `1.0~rc1`.
+    pub(crate) fn prerelease_channel(&self) -> Option<&str> {
+        let span = self.prerelease_span()?;
+        Tokens::new(&self.0[span])
+            .find(|t| t.kind() == VersionTokenKind::Alpha)
+            .map(|t| t.as_str())
+    }
+
+    /// Iterate over the tokens of the version.
+    ///
+    /// Invalid characters are skipped, just like during comparison.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::{Version, VersionTokenKind};
+    ///
+    /// let version = Version::from("1.0~rc1");
+    /// let kinds: Vec<_> = version.tokens().map(|t| t.kind()).collect();
+    ///
+    /// assert_eq!(
+    ///     kinds,
+    ///     [
+    ///         VersionTokenKind::Numeric,
+    ///         VersionTokenKind::Dot,
+    ///         VersionTokenKind::Numeric,
+    ///         VersionTokenKind::Tilde,
+    ///         VersionTokenKind::Alpha,
+    ///         VersionTokenKind::Numeric,
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn tokens(&self) -> Tokens<'_> {
+        Tokens::new(&self.0)
+    }
+
+    /// Return the number of tokens of the version, e.g. 6 for `1.0~rc1`.
+    ///
+    /// Invalid characters are skipped, just like in [`Version::tokens`].
+    #[must_use]
+    pub fn segment_count(&self) -> usize {
+        self.tokens().count()
+    }
+
+    /// Return the number of numerical tokens of the version, e.g. 3 for `1.0~rc1`.
+    #[must_use]
+    pub fn numeric_segment_count(&self) -> usize {
+        self.tokens()
+            .filter(|t| t.kind() == VersionTokenKind::Numeric)
+            .count()
+    }
+
+    /// Return the byte range and kind of each token of the version.
+    ///
+    /// The ranges index into [`Version::as_str`]. Invalid characters don't belong to any token,
+    /// so they show up as gaps between the ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::{Version, VersionTokenKind};
+    ///
+    /// let version = Version::from("1 rc");
+    ///
+    /// assert_eq!(
+    ///     version.segment_spans(),
+    ///     [(0..1, VersionTokenKind::Numeric), (2..4, VersionTokenKind::Alpha)]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn segment_spans(&self) -> Vec<(Range<usize>, VersionTokenKind)> {
+        let mut spans = Vec::new();
+        let mut scanner = Scanner::new(self.as_bytes());
+        while let Some(token) = scanner.next_token() {
+            spans.push((token.start..token.start + token.bytes.len(), token.kind));
+        }
+        spans
+    }
+
+    /// Return the run of letters the version starts with, e.g. `abc` for `abc-5`.
+    ///
+    /// Leading invalid characters are skipped like during comparison. Returns `None` if the
+    /// version starts with a number or a separator instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert_eq!(Version::from("abc-5").leading_alpha(), Some("abc"));
+    /// assert_eq!(Version::from("5.2").leading_alpha(), None);
+    /// ```
+    #[must_use]
+    pub fn leading_alpha(&self) -> Option<&str> {
+        self.tokens()
+            .next()
+            .filter(|t| t.kind() == VersionTokenKind::Alpha)
+            .map(|t| t.as_str())
+    }
+
+    /// Return the value of the number the version starts with, e.g. `7` for `007.1`.
+    ///
+    /// Leading invalid characters are skipped like during comparison. Returns `None` if the
+    /// version starts with letters or a separator instead or if the number doesn't fit into a
+    /// `u64`.
+    #[must_use]
+    pub fn leading_number(&self) -> Option<u64> {
+        self.leading_numeric_token().and_then(parse_digits)
+    }
+
+    /// Return the number of digits of the number the version starts with, including leading
+    /// zeros.
+    ///
+    /// This allows keeping the zero padding, which doesn't matter for the comparison, when
+    /// rewriting a version. Returns `None` in the same cases as [`Version::leading_number`],
+    /// except that numbers of any size are supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let version = Version::from("007.1");
+    ///
+    /// assert_eq!(version.leading_number(), Some(7));
+    /// assert_eq!(version.leading_number_width(), Some(3));
+    /// ```
+    #[must_use]
+    pub fn leading_number_width(&self) -> Option<usize> {
+        self.leading_numeric_token().map(str::len)
+    }
+
+    /// Return the value of the last number in the version, e.g. `45` for `1.2-45`.
+    ///
+    /// Returns `None` if the version doesn't end with a number, e.g. `1.2rc`, or if the number
+    /// doesn't fit into a `u64`. Invalid characters at the end are skipped like during comparison.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert_eq!(Version::from("1.2-045").trailing_number(), Some(45));
+    /// assert_eq!(Version::from("1.2rc").trailing_number(), None);
+    /// ```
+    #[must_use]
+    pub fn trailing_number(&self) -> Option<u64> {
+        self.tokens()
+            .last()
+            .filter(|t| t.kind() == VersionTokenKind::Numeric)
+            .and_then(|t| parse_digits(t.as_str()))
+    }
+
+    fn leading_numeric_token(&self) -> Option<&str> {
+        self.tokens()
+            .next()
+            .filter(|t| t.kind() == VersionTokenKind::Numeric)
+            .map(|t| t.as_str())
+    }
+
+    /// Return the first date embedded in the version as `(year, month, day)`.
+    ///
+    /// A date is a numerical run of exactly eight digits in `YYYYMMDD` format with a valid month
+    /// and day, e.g. in `git-20240115` or `1.0^20240115`. Comparing versions already orders such
+    /// dates chronologically, this merely extracts them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert_eq!(Version::from("git-20240115").snapshot_date(), Some((2024, 1, 15)));
+    /// assert_eq!(Version::from("1.2.3").snapshot_date(), None);
+    /// ```
+    #[must_use]
+    pub fn snapshot_date(&self) -> Option<(u16, u8, u8)> {
+        self.tokens()
+            .filter(|t| t.kind() == VersionTokenKind::Numeric && t.as_str().len() == 8)
+            .find_map(|t| {
+                let digits = t.as_str();
+                let year = digits[..4].parse().ok()?;
+                let month = digits[4..6].parse().ok()?;
+                let day = digits[6..].parse().ok()?;
+                (day >= 1 && day <= days_in_month(year, month)?).then_some((year, month, day))
+            })
+    }
+
+    /// Iterate over the values of all numerical runs of the version.
+    ///
+    /// Runs whose value doesn't fit into a `u64` are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let version = Version::from("1.20.3-4");
+    ///
+    /// assert!(version.iter_numeric().eq([1, 20, 3, 4]));
+    /// ```
+    pub fn iter_numeric(&self) -> impl Iterator<Item = u64> + '_ {
+        self.tokens()
+            .filter(|t| t.kind() == VersionTokenKind::Numeric)
+            .filter_map(|t| t.as_str().parse().ok())
+    }
+
+    /// Apply `f` to the value of every numerical run and rebuild the version.
+    ///
+    /// Everything but the numerical runs is kept as is. Leading zeros are lost since the runs are
+    /// replaced by their new values. Returns `None` if any run doesn't fit into a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let version = Version::from("1.2.3~rc1");
+    ///
+    /// assert_eq!(version.map_numeric(|n| n + 1), Some(Version::from("2.3.4~rc2")));
+    /// ```
+    pub fn map_numeric<F: Fn(u64) -> u64>(&self, f: F) -> Option<Version> {
+        let mut s = String::with_capacity(self.0.len());
+        let mut rest = self.0.as_str();
+        while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+            s.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let digits = leading_digits(rest);
+            s.push_str(&f(digits.parse().ok()?).to_string());
+            rest = &rest[digits.len()..];
+        }
+        s.push_str(rest);
+        Some(Self(s))
+    }
+
+    /// Iterate over the `.`-separated components of the version.
+    ///
+    /// Empty components are preserved, so `"1.2."` has three components, the last one being
+    /// empty. Likewise, the empty version consists of a single empty component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let version = Version::from("123.45-67.89");
+    ///
+    /// assert!(version.split_components().eq(["123", "45-67", "89"]));
+    /// ```
+    #[must_use]
+    pub fn split_components(&self) -> impl DoubleEndedIterator<Item = &str> {
+        self.0.split('.')
+    }
+
+    /// Iterate over the `.`-separated components of the version from last to first.
+    ///
+    /// This yields the same components as [`Version::split_components`] in reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let version = Version::from("123.45-67.89");
+    ///
+    /// assert!(version.rsplit_components().eq(["89", "45-67", "123"]));
+    /// ```
+    #[must_use]
+    pub fn rsplit_components(&self) -> impl DoubleEndedIterator<Item = &str> {
+        self.0.rsplit('.')
+    }
+
+    /// Return the last `.`-separated component of the version.
+    ///
+    /// Like with [`Version::split_components`], a trailing `.` results in an empty last
+    /// component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert_eq!(Version::from("123.45-67.89").last_component(), Some("89"));
+    /// assert_eq!(Version::from("1.2.").last_component(), Some(""));
+    /// ```
+    #[must_use]
+    pub fn last_component(&self) -> Option<&str> {
+        self.rsplit_components().next()
+    }
+
+    /// Return the `i`-th `.`-separated component of the version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let version = Version::from("123.45-67.89");
+    ///
+    /// assert_eq!(version.component(1), Some("45-67"));
+    /// assert_eq!(version.component(3), None);
+    /// ```
+    #[must_use]
+    pub fn component(&self, i: usize) -> Option<&str> {
+        self.split_components().nth(i)
+    }
+
+    /// Check whether any `.`-separated component of the version compares as equal to `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let version = Version::from("1.02.3");
+    ///
+    /// assert!(version.contains_component("2"));
+    /// assert!(!version.contains_component("4"));
+    /// ```
+    #[must_use]
+    pub fn contains_component(&self, value: &str) -> bool {
+        self.split_components()
+            .any(|c| strverscmp(c, value) == Ordering::Equal)
+    }
+
+    /// Return a copy of the version with the `i`-th `.`-separated component replaced by `value`.
+    ///
+    /// Returns `None` if there is no `i`-th component or if `value` contains a `.` or any
+    /// character that isn't valid in a version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let version = Version::from("1.2.3");
+    ///
+    /// assert_eq!(version.with_component(1, "5"), Some(Version::from("1.5.3")));
+    /// assert_eq!(version.with_component(3, "5"), None);
+    /// assert_eq!(version.with_component(1, "5.0"), None);
+    /// ```
+    #[must_use]
+    pub fn with_component(&self, i: usize, value: &str) -> Option<Version> {
+        if i >= self.component_count()
+            || !value.chars().all(|c| c != '.' && is_valid_version_char(c))
+        {
+            return None;
+        }
+        let mut s = String::with_capacity(self.0.len() + value.len());
+        for (j, component) in self.split_components().enumerate() {
+            if j > 0 {
+                s.push('.');
             }
+            s.push_str(if j == i { value } else { component });
+        }
+        Some(Self(s))
+    }
+
+    /// Return whether the version continues the series of `base` with further components.
+    ///
+    /// The leading `.`-separated components have to compare as equal to those of `base` one by
+    /// one, and the version must have more components and be newer than `base`. Comparing whole
+    /// components means that `5.20` is not a point release of `5.2`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let base = Version::from("1.2");
+    ///
+    /// assert!(Version::from("1.2.3").is_point_release_of(&base));
+    /// assert!(!Version::from("1.3").is_point_release_of(&base));
+    /// assert!(!Version::from("1.20").is_point_release_of(&base));
+    /// ```
+    #[must_use]
+    pub fn is_point_release_of(&self, base: &Version) -> bool {
+        let mut components = self.split_components();
+        let same_series = base.split_components().all(|b| {
+            components
+                .next()
+                .is_some_and(|c| strverscmp(c, b) == Ordering::Equal)
+        });
+        same_series && components.next().is_some() && self > base
+    }
 
-            let ordering = left_alpha_prefix.cmp(&right_alpha_prefix);
-            if ordering != Ordering::Equal {
-                return ordering;
+    /// Check whether the first `depth` `.`-separated components of both versions compare as equal.
+    ///
+    /// Whole components are compared, so `5.2` and `5.20` are in the same series at depth 1 but
+    /// not at depth 2. If either version has fewer than `depth` components, they are not in the
+    /// same series. Any two versions are in the same series at depth 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let version = Version::from("5.2.1");
+    ///
+    /// assert!(version.same_series(&Version::from("5.2.9"), 2));
+    /// assert!(!version.same_series(&Version::from("5.3.0"), 2));
+    /// ```
+    #[must_use]
+    pub fn same_series(&self, other: &Version, depth: usize) -> bool {
+        let mut components = self.split_components().zip(other.split_components());
+        (0..depth).all(|_| {
+            components
+                .next()
+                .is_some_and(|(a, b)| strverscmp(a, b) == Ordering::Equal)
+        })
+    }
+
+    /// Return the leading `.`-separated components the version shares with `other`.
+    ///
+    /// Components are compared with [`strverscmp`], so `01` and `1` are shared. The result is a
+    /// slice of the version itself and doesn't include the `.` after the last shared component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert_eq!(Version::from("1.2.3").common_prefix(&Version::from("1.2.9")), "1.2");
+    /// assert_eq!(Version::from("1.2.3").common_prefix(&Version::from("2.2.3")), "");
+    /// ```
+    #[must_use]
+    pub fn common_prefix(&self, other: &Version) -> &str {
+        &self.0[..self.common_prefix_len(other).unwrap_or(0)]
+    }
+
+    /// Return the part of the version after the components it shares with `other`.
+    ///
+    /// This is what is left of the version after its [`Version::common_prefix`] and the `.`
+    /// following it. If all of the components of the version are shared, e.g. because it is
+    /// a prefix of `other`, the result is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let a = Version::from("1.2.3");
+    /// let b = Version::from("1.2.9");
+    ///
+    /// assert_eq!(a.suffix_after_common(&b), "3");
+    /// assert_eq!(b.suffix_after_common(&a), "9");
+    /// ```
+    #[must_use]
+    pub fn suffix_after_common(&self, other: &Version) -> &str {
+        match self.common_prefix_len(other) {
+            Some(len) => self.0.get(len + 1..).unwrap_or_default(),
+            None => &self.0,
+        }
+    }
+
+    /// Return the length of the shared components or `None` if not even the first one is shared.
+    fn common_prefix_len(&self, other: &Version) -> Option<usize> {
+        self.split_components()
+            .zip(other.split_components())
+            .take_while(|(a, b)| strverscmp(a, b) == Ordering::Equal)
+            .fold(None, |len, (a, _)| {
+                Some(len.map_or(a.len(), |len| len + 1 + a.len()))
+            })
+    }
+
+    /// List the `.`-separated components that differ between the version and `other`.
+    ///
+    /// Components are compared by position and treated as unchanged if they compare as equal, so
+    /// `01` and `1` are the same. Components that only one of the versions has are reported with
+    /// `None` on the other side.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::{ComponentChange, Version};
+    ///
+    /// let changes = Version::from("1.2.3").component_diff(&Version::from("1.2.9"));
+    ///
+    /// assert_eq!(
+    ///     changes,
+    ///     [ComponentChange { index: 2, old: Some("3".into()), new: Some("9".into()) }]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn component_diff(&self, other: &Version) -> Vec<ComponentChange> {
+        let mut old = self.split_components();
+        let mut new = other.split_components();
+        let mut changes = Vec::new();
+        for index in 0.. {
+            let (o, n) = (old.next(), new.next());
+            let differs = match (o, n) {
+                (None, None) => break,
+                (Some(o), Some(n)) => strverscmp(o, n) != Ordering::Equal,
+                _ => true,
+            };
+            if differs {
+                changes.push(ComponentChange {
+                    index,
+                    old: o.map(String::from),
+                    new: n.map(String::from),
+                });
             }
         }
+        changes
     }
-}
 
-fn compare_special_char(char: char, left: Option<char>, right: Option<char>) -> Ordering {
-    let left_bool = !left.is_some_and(|c| c == char);
-    let right_bool = !right.is_some_and(|c| c == char);
-    left_bool.cmp(&right_bool)
-}
+    /// Check whether two versions are equal except for their last component differing by at most
+    /// `tolerance`.
+    ///
+    /// Both versions need the same number of `.`-separated components and all but the last have
+    /// to compare as equal. If either last component isn't purely numerical, this falls back to
+    /// [`Version::is_equivalent`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let version = Version::from("1.2.3");
+    ///
+    /// assert!(version.approx_eq(&Version::from("1.2.5"), 2));
+    /// assert!(!version.approx_eq(&Version::from("1.2.6"), 2));
+    /// assert!(!version.approx_eq(&Version::from("1.2.3a"), 2));
+    /// ```
+    #[must_use]
+    pub fn approx_eq(&self, other: &Version, tolerance: u64) -> bool {
+        let last = |v: &Version| v.split_components().next_back().and_then(parse_digits);
+        let (Some(a), Some(b)) = (last(self), last(other)) else {
+            return self.is_equivalent(other);
+        };
+        self.same_except_last(other) && a.abs_diff(b) <= tolerance
+    }
 
-fn is_valid_version_char(c: char) -> bool {
-    c.is_ascii_alphanumeric() || matches!(c, '~' | '-' | '^' | '.')
+    /// Check whether two versions are consecutive, e.g. `1.2.3` and `1.2.4`.
+    ///
+    /// Both versions need the same number of `.`-separated components, all but the last have to
+    /// compare as equal and the last ones have to differ by exactly one, in either direction. If
+    /// either last component isn't purely numerical, the versions are not adjacent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let version = Version::from("1.2.3");
+    ///
+    /// assert!(version.is_adjacent_to(&Version::from("1.2.4")));
+    /// assert!(!version.is_adjacent_to(&Version::from("1.2.5")));
+    /// ```
+    #[must_use]
+    pub fn is_adjacent_to(&self, other: &Version) -> bool {
+        let last = |v: &Version| v.split_components().next_back().and_then(parse_digits);
+        let (Some(a), Some(b)) = (last(self), last(other)) else {
+            return false;
+        };
+        self.same_except_last(other) && a.abs_diff(b) == 1
+    }
+
+    /// Check whether both versions have the same number of components and all but the last
+    /// compare as equal.
+    fn same_except_last(&self, other: &Version) -> bool {
+        let count = self.component_count();
+        count == other.component_count()
+            && self
+                .split_components()
+                .zip(other.split_components())
+                .take(count - 1)
+                .all(|(a, b)| strverscmp(a, b) == Ordering::Equal)
+    }
+
+    /// Describe where and by how much `other` differs from the version.
+    ///
+    /// The first differing `.`-separated component is found like with [`diverging_component`]. If
+    /// both versions have that component and it is purely numerical on both sides, the delta is
+    /// the value in `other` minus the value in the version. This is only meant for display
+    /// purposes and not a metric. If all components compare as equal, the index is the number of
+    /// components, i.e. one past the last component, and there is no delta.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::{Version, VersionDistance};
+    ///
+    /// let distance = Version::from("1.2.3").distance(&Version::from("1.2.9"));
+    ///
+    /// assert_eq!(
+    ///     distance,
+    ///     VersionDistance { component_index: 2, numeric_delta: Some(6) }
+    /// );
+    /// ```
+    #[must_use]
+    pub fn distance(&self, other: &Version) -> VersionDistance {
+        let Some(component_index) = diverging_component(&self.0, &other.0) else {
+            return VersionDistance {
+                component_index: self.component_count(),
+                numeric_delta: None,
+            };
+        };
+        let value = |v: &Version| {
+            v.component(component_index)
+                .and_then(parse_digits)
+                .and_then(|n| i64::try_from(n).ok())
+        };
+        let numeric_delta = match (value(self), value(other)) {
+            (Some(a), Some(b)) => b.checked_sub(a),
+            _ => None,
+        };
+        VersionDistance {
+            component_index,
+            numeric_delta,
+        }
+    }
+
+    /// Match the version against a pattern like `1.2.*`.
+    ///
+    /// The pattern is matched component by component. A `*` component matches exactly one
+    /// component of any value, so a trailing `*` doesn't match zero components and `1.2.*`
+    /// matches neither `1.2` nor `1.2.3.4`. All other components have to compare as equal. A `*`
+    /// inside a component is thus ignored like any other invalid character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert!(Version::from("1.2.3").matches_glob("1.2.*"));
+    /// assert!(!Version::from("1.3.0").matches_glob("1.2.*"));
+    /// assert!(!Version::from("1.2").matches_glob("1.2.*"));
+    /// ```
+    #[must_use]
+    pub fn matches_glob(&self, pattern: &str) -> bool {
+        let mut components = self.split_components();
+        pattern.split('.').all(|p| {
+            components
+                .next()
+                .is_some_and(|c| p == "*" || strverscmp(c, p) == Ordering::Equal)
+        }) && components.next().is_none()
+    }
+
+    /// Return the number of `.`-separated components of the version.
+    #[must_use]
+    pub fn component_count(&self) -> usize {
+        self.split_components().count()
+    }
+
+    /// Return the `.`-separated components of the version together with their kind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::{ComponentKind, Version};
+    ///
+    /// assert_eq!(
+    ///     Version::from("1.rc2.0").classified_components(),
+    ///     [
+    ///         ("1", ComponentKind::Numeric),
+    ///         ("rc2", ComponentKind::Mixed),
+    ///         ("0", ComponentKind::Numeric),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn classified_components(&self) -> Vec<(&str, ComponentKind)> {
+        self.split_components()
+            .map(|c| {
+                let kind = if c.is_empty() {
+                    ComponentKind::Mixed
+                } else if c.bytes().all(|b| b.is_ascii_digit()) {
+                    ComponentKind::Numeric
+                } else if c.bytes().all(|b| b.is_ascii_alphabetic()) {
+                    ComponentKind::Alpha
+                } else {
+                    ComponentKind::Mixed
+                };
+                (c, kind)
+            })
+            .collect()
+    }
+
+    /// Return the byte offsets of all characters that are not valid in a version.
+    ///
+    /// These characters are ignored during comparison.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert_eq!(Version::from("1_2+ä").invalid_char_positions(), [1, 3, 4]);
+    /// ```
+    #[must_use]
+    pub fn invalid_char_positions(&self) -> Vec<usize> {
+        self.0
+            .char_indices()
+            .filter(|&(_, c)| !is_valid_version_char(c))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Collect the diagnostics for `s` into a single report.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let report = Version::validate("12_3");
+    ///
+    /// assert_eq!(report.invalid_chars, [2]);
+    /// assert!(report.is_well_formed);
+    /// assert_eq!(report.normalized.as_str(), "12_3");
+    /// ```
+    #[must_use]
+    pub fn validate(s: &str) -> ValidationReport {
+        let version = Version::from(s);
+        ValidationReport {
+            invalid_chars: version.invalid_char_positions(),
+            is_well_formed: version.is_well_formed(),
+            normalized: version.normalized(),
+        }
+    }
+
+    /// Check whether the version looks like it was written on purpose.
+    ///
+    /// This is a heuristic to warn about probable typos, any string is still a valid version. A
+    /// version is considered well-formed if both of the following hold:
+    ///
+    /// - It contains at least one ASCII letter or digit, so `~-^.` and the empty version are not
+    ///   well-formed.
+    /// - It neither starts nor ends with a `.`, i.e. its first and last components aren't empty.
+    ///
+    /// Invalid characters are not checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert!(Version::from("1.2.3").is_well_formed());
+    /// assert!(!Version::from("~-^.").is_well_formed());
+    /// assert!(!Version::from(".1.2").is_well_formed());
+    /// ```
+    #[must_use]
+    pub fn is_well_formed(&self) -> bool {
+        self.0.bytes().any(|b| b.is_ascii_alphanumeric())
+            && !self.0.starts_with('.')
+            && !self.0.ends_with('.')
+    }
+
+    /// Return the core version without its release and patch parts.
+    ///
+    /// The version is cut at the first `-` or `^`, whichever comes first. A `~` pre-release part
+    /// before the cut is kept, one after it is dropped together with the rest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert_eq!(Version::from("1.0~rc1-5^2").core_version(), Version::from("1.0~rc1"));
+    /// ```
+    #[must_use]
+    pub fn core_version(&self) -> Version {
+        let end = self.0.find(['-', '^']).unwrap_or(self.0.len());
+        Self(self.0[..end].into())
+    }
+
+    /// Return the tokens of the version in a form that orders like the version.
+    ///
+    /// This is the structured counterpart of [`Version::sort_key`], meant for exporting versions
+    /// to systems that can't use this crate. Comparing two vectors lexicographically is the same
+    /// as comparing the versions. Invalid characters are skipped and the end of the version is
+    /// included as a last unit, which is what makes shorter versions compare correctly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let a = Version::from("1.0~rc1");
+    /// let b = Version::from("1.0");
+    ///
+    /// assert_eq!(a.comparison_vector().len(), 7);
+    /// assert_eq!(a.comparison_vector().cmp(&b.comparison_vector()), a.cmp(&b));
+    /// ```
+    #[must_use]
+    pub fn comparison_vector(&self) -> Vec<CompareUnit> {
+        let precedence = SeparatorPrecedence::UAPI;
+        let mut units: Vec<CompareUnit> = self
+            .tokens()
+            .map(|token| {
+                let payload = match token.kind() {
+                    VersionTokenKind::Alpha => ComparePayload::Alpha(token.as_str().into()),
+                    VersionTokenKind::Numeric => {
+                        let digits = token.as_str().trim_start_matches('0');
+                        let digits = if digits.is_empty() { "0" } else { digits };
+                        ComparePayload::Numeric {
+                            len: digits.len(),
+                            digits: digits.into(),
+                        }
+                    }
+                    _ => ComparePayload::None,
+                };
+                CompareUnit {
+                    rank: precedence.rank(Some(token.kind())),
+                    payload,
+                }
+            })
+            .collect();
+        units.push(CompareUnit {
+            rank: precedence.rank(None),
+            payload: ComparePayload::None,
+        });
+        units
+    }
+
+    /// Render the version with the separators given by `style`.
+    ///
+    /// All other characters, including invalid ones, are kept as they are. Other ecosystems may
+    /// order the result differently, e.g. `1.0-5` is older than `1.0.5`, but both render as `1.0.5`
+    /// with [`SeparatorStyle::DOTTED`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::{SeparatorStyle, Version};
+    ///
+    /// let version = Version::from("1.0~rc1-5^2");
+    ///
+    /// assert_eq!(version.format_with(&SeparatorStyle::DOTTED), "1.0~rc1.5.2");
+    /// ```
+    #[must_use]
+    pub fn format_with(&self, style: &SeparatorStyle) -> String {
+        let mut s = String::with_capacity(self.0.len());
+        for c in self.0.chars() {
+            match c {
+                '~' => s.push_str(style.tilde),
+                '-' => s.push_str(style.dash),
+                '^' => s.push_str(style.caret),
+                '.' => s.push_str(style.dot),
+                _ => s.push(c),
+            }
+        }
+        s
+    }
+
+    /// Return a key that orders like the version when compared bytewise.
+    ///
+    /// For any two versions `a` and `b`, `a.sort_key().cmp(&b.sort_key())` is the same as
+    /// `a.cmp(&b)`. Computing the key tokenizes the version once, so sorting a large slice with
+    /// [`slice::sort_by_cached_key`] can be faster than sorting it directly. The key is only meant
+    /// to be compared with other keys, its exact bytes aren't part of the API.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let mut versions = ["1.10", "1.9", "1.9~rc1", "1.09.1"].map(Version::from);
+    /// versions.sort_by_cached_key(Version::sort_key);
+    ///
+    /// assert_eq!(versions, ["1.9~rc1", "1.9", "1.09.1", "1.10"].map(Version::from));
+    /// ```
+    #[must_use]
+    pub fn sort_key(&self) -> Vec<u8> {
+        let precedence = SeparatorPrecedence::UAPI;
+        let mut key = Vec::with_capacity(self.0.len() + 1);
+        let mut scanner = Scanner::new(self.as_bytes());
+        while let Some(token) = scanner.next_token() {
+            key.push(precedence.rank(Some(token.kind)));
+            match token.kind {
+                // Letters are never zero, so a shorter run sorts before a longer one that starts
+                // the same way.
+                VersionTokenKind::Alpha => {
+                    key.extend_from_slice(token.bytes);
+                    key.push(0);
+                }
+                // Prefix the digits with their count so that longer numbers sort after shorter
+                // ones.
+                VersionTokenKind::Numeric => {
+                    let digits = strip_leading_zeros(token.bytes);
+                    match u8::try_from(digits.len()) {
+                        Ok(len) if len < u8::MAX => key.push(len),
+                        _ => {
+                            key.push(u8::MAX);
+                            key.extend_from_slice(&(digits.len() as u64).to_be_bytes());
+                        }
+                    }
+                    key.extend_from_slice(digits);
+                }
+                _ => {}
+            }
+        }
+        key.push(precedence.rank(None));
+        key
+    }
+
+    /// Return the canonical form of the version.
+    ///
+    /// See [`NormalizedVersion`] for the guarantees it provides.
+    #[must_use]
+    pub fn normalized(&self) -> NormalizedVersion {
+        NormalizedVersion::new(self)
+    }
+
+    /// Clear `buf` and write the canonical form of the version into it.
+    ///
+    /// This is the same as [`Version::normalized`] but allows reusing the allocation of `buf`
+    /// when normalizing many versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let mut buf = String::new();
+    /// Version::from("01.002~rc_1").normalize_into(&mut buf);
+    ///
+    /// assert_eq!(buf, "1.2~rc1");
+    /// ```
+    pub fn normalize_into(&self, buf: &mut String) {
+        buf.clear();
+        normalized::write_normalized(self, buf);
+    }
+
+    /// Return the epoch of a version like `2:1.0`.
+    ///
+    /// The epoch is a number followed by `:` at the start of the version. It is not part of the
+    /// specification, so comparisons don't treat it specially: `:` is ignored like any other
+    /// invalid character. Returns `None` if there is no epoch or it doesn't fit into a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let version = Version::from("2:1.0");
+    ///
+    /// assert_eq!(version.epoch(), Some(2));
+    /// assert_eq!(version.without_epoch(), "1.0");
+    /// assert_eq!(Version::from("1.0").epoch(), None);
+    /// ```
+    #[must_use]
+    pub fn epoch(&self) -> Option<u64> {
+        self.split_epoch().and_then(|(epoch, _)| epoch.parse().ok())
+    }
+
+    /// Return the version without its epoch, see [`Version::epoch`].
+    #[must_use]
+    pub fn without_epoch(&self) -> &str {
+        self.split_epoch().map_or(&self.0, |(_, rest)| rest)
+    }
+
+    fn split_epoch(&self) -> Option<(&str, &str)> {
+        let digits = leading_digits(&self.0);
+        let rest = self.0[digits.len()..].strip_prefix(':')?;
+        (!digits.is_empty()).then_some((digits, rest))
+    }
+
+    /// Return the upstream part of the version.
+    ///
+    /// A version is made up of the upstream part, followed by an optional `~` pre-release part,
+    /// an optional `^` patch part and an optional `-` release part, in this order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let version = Version::from("1.0~rc1^2-5");
+    ///
+    /// assert_eq!(version.upstream(), "1.0");
+    /// assert_eq!(version.prerelease_tag(), Some("rc1"));
+    /// assert_eq!(version.patch(), Some("2"));
+    /// assert_eq!(version.release(), Some("5"));
+    /// ```
+    #[must_use]
+    pub fn upstream(&self) -> &str {
+        let head = self.head();
+        &head[..head.find(['~', '^']).unwrap_or(head.len())]
+    }
+
+    /// Return the pre-release part without its leading `~`.
+    ///
+    /// See [`Version::strip_prerelease`] for what counts as the pre-release part. In particular
+    /// `1.0^2~rc1` has no pre-release tag, the `~rc1` belongs to its [patch](Version::patch) part.
+    #[must_use]
+    pub fn prerelease_tag(&self) -> Option<&str> {
+        self.prerelease_span()
+            .map(|span| &self.0[span.start + 1..span.end])
+    }
+
+    /// Return the pre-release tag up to its first `.`, e.g. `rc1` for `1.0~rc1.git20240101`.
+    ///
+    /// This only extracts the tag for display, the version itself still compares with the full
+    /// pre-release part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// assert_eq!(Version::from("1.0~rc1.git20240101").prerelease_primary(), Some("rc1"));
+    /// ```
+    #[must_use]
+    pub fn prerelease_primary(&self) -> Option<&str> {
+        self.prerelease_tag()
+            .map(|tag| tag.split('.').next().unwrap_or_default())
+    }
+
+    /// Return the patch part without its leading `^`.
+    #[must_use]
+    pub fn patch(&self) -> Option<&str> {
+        let head = self.head();
+        head.find('^').map(|i| &head[i + 1..])
+    }
+
+    /// Return the release part without its leading `-`.
+    #[must_use]
+    pub fn release(&self) -> Option<&str> {
+        self.split_at('-').1
+    }
+
+    /// Split the version at the first occurrence of `separator`.
+    ///
+    /// Returns the part before the separator and, if the separator occurs, the part after it.
+    /// This is the primitive behind [`Version::upstream`] and friends and works for any
+    /// character, although only `~`, `-`, `^` and `.` are separators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::Version;
+    ///
+    /// let version = Version::from("1.0-5^2");
+    ///
+    /// assert_eq!(version.split_at('-'), ("1.0", Some("5^2")));
+    /// assert_eq!(version.split_at('~'), ("1.0-5^2", None));
+    /// ```
+    #[must_use]
+    pub fn split_at(&self, separator: char) -> (&str, Option<&str>) {
+        match self.0.split_once(separator) {
+            Some((head, tail)) => (head, Some(tail)),
+            None => (&self.0, None),
+        }
+    }
+
+    /// Return the version up to the first `-`.
+    fn head(&self) -> &str {
+        self.split_at('-').0
+    }
+
+    /// Return the byte range of the pre-release part including its leading `~`.
+    fn prerelease_span(&self) -> Option<Range<usize>> {
+        let head = self.head();
+        let start = head.find(['~', '^'])?;
+        if !head[start..].starts_with('~') {
+            return None;
+        }
+        let end = head[start..].find('^').map_or(head.len(), |i| start + i);
+        Some(start..end)
+    }
+}
+
+impl From<&str> for Version {
+    fn from(s: &str) -> Self {
+        Self(s.into())
+    }
+}
+
+impl From<String> for Version {
+    fn from(s: String) -> Self {
+        Self(s)
+    }
+}
+
+impl From<&String> for Version {
+    fn from(s: &String) -> Self {
+        Self(s.into())
+    }
+}
+
+impl From<u64> for Version {
+    fn from(n: u64) -> Self {
+        Self(n.to_string())
+    }
+}
+
+/// Build a `major.minor.patch` version.
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::Version;
+///
+/// assert_eq!(Version::from((1, 2, 3)), Version::from("1.2.3"));
+/// ```
+impl From<(u64, u64, u64)> for Version {
+    fn from((major, minor, patch): (u64, u64, u64)) -> Self {
+        Self(format!("{major}.{minor}.{patch}"))
+    }
+}
+
+/// Iterate over the tokens of the version, see [`Version::tokens`].
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::{Version, VersionTokenKind};
+///
+/// let version = Version::from("1.0~rc1");
+/// let mut tilde = None;
+///
+/// for token in &version {
+///     if token.kind() == VersionTokenKind::Tilde {
+///         tilde = Some(token.as_str());
+///     }
+/// }
+///
+/// assert_eq!(tilde, Some("~"));
+/// ```
+// `Version::tokens` plays the role of `iter`.
+#[allow(clippy::into_iter_without_iter)]
+impl<'a> IntoIterator for &'a Version {
+    type Item = VersionToken<'a>;
+    type IntoIter = Tokens<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tokens()
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        strverscmp(&self.0, &other.0)
+    }
+}
+
+/// Compare a purely numerical version with a number.
+///
+/// Versions that consist of anything but ASCII digits can't be meaningfully compared with a number
+/// and thus return `None`, e.g. `1.0` or `1~rc1`.
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::Version;
+///
+/// assert!(Version::from("225") > 224);
+/// assert!(Version::from("0225") == 225);
+/// assert_eq!(Version::from("225.1").partial_cmp(&225), None);
+/// ```
+impl PartialOrd<u64> for Version {
+    fn partial_cmp(&self, other: &u64) -> Option<Ordering> {
+        if self.0.is_empty() || !self.0.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        // All digits, so parsing can only fail on overflow.
+        Some(
+            self.0
+                .parse::<u64>()
+                .map_or(Ordering::Greater, |n| n.cmp(other)),
+        )
+    }
+}
+
+impl PartialEq<u64> for Version {
+    fn eq(&self, other: &u64) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd<Version> for u64 {
+    fn partial_cmp(&self, other: &Version) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+impl PartialEq<Version> for u64 {
+    fn eq(&self, other: &Version) -> bool {
+        other == self
+    }
+}
+
+/// A changed component as returned by [`Version::component_diff`].
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ComponentChange {
+    pub index: usize,
+    /// The component in the old version or `None` if it was added.
+    pub old: Option<String>,
+    /// The component in the new version or `None` if it was removed.
+    pub new: Option<String>,
+}
+
+/// The diagnostics for a version string as returned by [`Version::validate`].
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ValidationReport {
+    /// See [`Version::invalid_char_positions`].
+    pub invalid_chars: Vec<usize>,
+    /// See [`Version::is_well_formed`].
+    pub is_well_formed: bool,
+    /// See [`Version::normalized`].
+    pub normalized: NormalizedVersion,
+}
+
+/// Where two versions differ, as returned by [`Version::distance`].
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub struct VersionDistance {
+    /// The index of the first differing `.`-separated component.
+    pub component_index: usize,
+    /// The numerical difference in that component, if it is purely numerical in both versions.
+    pub numeric_delta: Option<i64>,
+}
+
+/// A single token of a version as returned by [`Version::comparison_vector`].
+///
+/// Units compare by their rank first and by their payload second, which makes comparing
+/// `Vec<CompareUnit>` lexicographically the same as comparing the versions.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct CompareUnit {
+    /// The rank of the kind of the token as given by [`SeparatorPrecedence::UAPI`].
+    pub rank: u8,
+    pub payload: ComparePayload,
+}
+
+/// The value of a [`CompareUnit`].
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum ComparePayload {
+    /// Separators and the end of the version carry no value.
+    None,
+    /// A run of letters, compared bytewise.
+    Alpha(String),
+    /// A run of digits without leading zeros. Numbers with fewer digits are smaller, numbers with
+    /// the same number of digits compare bytewise.
+    Numeric { len: usize, digits: String },
+}
+
+/// The kind of a component as returned by [`Version::classified_components`].
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum ComponentKind {
+    /// Only ASCII digits, e.g. `12`.
+    Numeric,
+    /// Only ASCII letters, e.g. `rc`.
+    Alpha,
+    /// Anything else, e.g. `rc2`, `45-67` or an empty component.
+    Mixed,
+}
+
+/// How a version relates to another, as returned by [`relationship`].
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum Relationship {
+    /// The second version is a newer release of the same upstream version.
+    SameUpstreamNewer,
+    /// The second version is an older release of the same upstream version.
+    SameUpstreamOlder,
+    /// Both versions are the same release of the same upstream version.
+    SameUpstreamEqual,
+    /// The upstream versions differ.
+    DifferentUpstream,
+}
+
+/// Classify how `b` relates to `a`.
+///
+/// The [upstream](Version::upstream) parts have to compare as equal, otherwise the versions are
+/// unrelated. Then the [release](Version::release) parts decide whether `b` is newer or older,
+/// where a missing release part counts as empty. Pre-release and patch parts are not taken into
+/// account, so `1.0~rc1-5` and `1.0-5` are the same release.
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::{relationship, Relationship, Version};
+///
+/// let a = Version::from("1.0-5");
+///
+/// assert_eq!(relationship(&a, &Version::from("1.0-7")), Relationship::SameUpstreamNewer);
+/// assert_eq!(relationship(&a, &Version::from("1.0~rc1-5")), Relationship::SameUpstreamEqual);
+/// assert_eq!(relationship(&a, &Version::from("2.0-1")), Relationship::DifferentUpstream);
+/// ```
+#[must_use]
+pub fn relationship(a: &Version, b: &Version) -> Relationship {
+    if strverscmp(a.upstream(), b.upstream()) != Ordering::Equal {
+        return Relationship::DifferentUpstream;
+    }
+    match strverscmp(
+        b.release().unwrap_or_default(),
+        a.release().unwrap_or_default(),
+    ) {
+        Ordering::Greater => Relationship::SameUpstreamNewer,
+        Ordering::Less => Relationship::SameUpstreamOlder,
+        Ordering::Equal => Relationship::SameUpstreamEqual,
+    }
+}
+
+/// Check whether updating from `from` to `to` would be a downgrade, i.e. whether `to` is older.
+///
+/// Pre-releases sort before their release, so going from `1.0` to `1.0~rc1` is a downgrade, while
+/// going from `1.0~rc1` to `1.0` is not. Versions that compare as equal, like `1.0` and `1.00`,
+/// are not a downgrade either.
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::{is_downgrade, Version};
+///
+/// assert!(is_downgrade(&Version::from("1.0"), &Version::from("1.0~rc1")));
+/// assert!(!is_downgrade(&Version::from("1.0"), &Version::from("1.1")));
+/// ```
+#[must_use]
+pub fn is_downgrade(from: &Version, to: &Version) -> bool {
+    to < from
+}
+
+/// The kind of an update as returned by [`update_kind`].
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum UpdateKind {
+    /// The first component of the upstream part changed, e.g. `1.2` to `2.0`.
+    Major,
+    /// The second component of the upstream part changed, e.g. `1.2` to `1.3`.
+    Minor,
+    /// A later component of the upstream part or the `^` patch part changed, e.g. `1.2.3` to
+    /// `1.2.4` or `1.2^1` to `1.2^2`.
+    Patch,
+    /// Only the `~` pre-release part changed, e.g. `1.0~rc1` to `1.0~rc2` or `1.0`.
+    PreReleaseOnly,
+    /// Only the `-` release part changed, e.g. `1.0-1` to `1.0-2`.
+    ReleaseOnly,
+    /// The versions compare as equal.
+    None,
+    /// The new version is older.
+    Downgrade,
+}
+
+/// Classify the update from `from` to `to`.
+///
+/// The [upstream](Version::upstream) parts are compared component by component like with
+/// [`diverging_component`]. If they're equal, the pre-release, patch and release parts are
+/// looked at, in that order.
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::{update_kind, UpdateKind, Version};
+///
+/// let from = Version::from("1.2.3");
+///
+/// assert_eq!(update_kind(&from, &Version::from("1.3.0")), UpdateKind::Minor);
+/// assert_eq!(update_kind(&from, &Version::from("1.2.3-2")), UpdateKind::ReleaseOnly);
+/// assert_eq!(update_kind(&from, &Version::from("1.2.2")), UpdateKind::Downgrade);
+/// ```
+#[must_use]
+pub fn update_kind(from: &Version, to: &Version) -> UpdateKind {
+    let differs = |a: Option<&str>, b: Option<&str>| match (a, b) {
+        (Some(a), Some(b)) => strverscmp(a, b) != Ordering::Equal,
+        (a, b) => a.is_some() != b.is_some(),
+    };
+    match to.cmp(from) {
+        Ordering::Less => return UpdateKind::Downgrade,
+        Ordering::Equal => return UpdateKind::None,
+        Ordering::Greater => {}
+    }
+    match diverging_component(from.upstream(), to.upstream()) {
+        Some(0) => UpdateKind::Major,
+        Some(1) => UpdateKind::Minor,
+        Some(_) => UpdateKind::Patch,
+        None if differs(from.prerelease_tag(), to.prerelease_tag()) => UpdateKind::PreReleaseOnly,
+        None if differs(from.patch(), to.patch()) => UpdateKind::Patch,
+        None => UpdateKind::ReleaseOnly,
+    }
+}
+
+/// Ensure that `candidate` is strictly newer than `previous`, e.g. before publishing a release.
+///
+/// # Errors
+///
+/// Returns an error carrying both versions if `candidate` is older than or compares as equal to
+/// `previous`.
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::{ensure_newer, Version};
+///
+/// let previous = Version::from("1.0");
+///
+/// assert!(ensure_newer(&previous, &Version::from("1.1")).is_ok());
+/// assert!(ensure_newer(&previous, &Version::from("1.00")).is_err());
+/// ```
+pub fn ensure_newer(previous: &Version, candidate: &Version) -> Result<(), DowngradeError> {
+    if candidate > previous {
+        Ok(())
+    } else {
+        Err(DowngradeError {
+            previous: previous.clone(),
+            candidate: candidate.clone(),
+        })
+    }
+}
+
+/// An error returned by [`ensure_newer`].
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct DowngradeError {
+    pub previous: Version,
+    pub candidate: Version,
+}
+
+impl fmt::Display for DowngradeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "version {} is not newer than {}",
+            self.candidate, self.previous
+        )
+    }
+}
+
+impl core::error::Error for DowngradeError {}
+
+/// Common architecture names as used in package versions, for [`Version::strip_arch_suffix`].
+pub const COMMON_ARCHES: &[&str] = &[
+    "x86_64",
+    "i686",
+    "i586",
+    "i386",
+    "aarch64",
+    "armv7hl",
+    "armv7l",
+    "armhfp",
+    "ppc64le",
+    "ppc64",
+    "s390x",
+    "riscv64",
+    "loongarch64",
+    "amd64",
+    "arm64",
+    "noarch",
+];
+
+/// Check that `==` and [`Ord`] agree on `a` and `b`.
+///
+/// Because `==` compares the underlying strings, they don't always agree, e.g. for `0_` and `0`.
+/// Call this where code relies on both, like when storing versions in a
+/// [`BTreeSet`](alloc::collections::BTreeSet), to catch such versions during development. In
+/// release builds this does nothing.
+///
+/// # Panics
+///
+/// Panics in debug builds if `a == b` but `a.cmp(b)` isn't [`Ordering::Equal`] or vice versa.
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::{debug_assert_consistent, Version};
+///
+/// debug_assert_consistent(&Version::from("1.0"), &Version::from("1.0"));
+/// debug_assert_consistent(&Version::from("1.0"), &Version::from("2.0"));
+/// ```
+#[track_caller]
+pub fn debug_assert_consistent(a: &Version, b: &Version) {
+    debug_assert!(
+        (a == b) == a.is_equivalent(b),
+        "`==` and `Ord` disagree on {a:?} and {b:?}"
+    );
+}
+
+/// Compare two version strings.
+///
+/// Only ASCII letters, digits and the separators `~`, `-`, `^` and `.` are significant. All other
+/// characters, including whitespace, are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use uapi_version::strverscmp;
+///
+/// assert_eq!(strverscmp("1.0.0", "2.0.0"), Ordering::Less)
+/// ```
+#[must_use]
+pub fn strverscmp(a: &str, b: &str) -> Ordering {
+    compare(
+        a.as_bytes(),
+        b.as_bytes(),
+        SeparatorPrecedence::UAPI,
+        &CompareOptions::default(),
+    )
+}
+
+/// Compare two values that can be viewed as version strings, e.g. [`String`] or
+/// [`Cow<str>`](alloc::borrow::Cow).
+///
+/// This is the same as [`strverscmp`], which only takes `&str` so that it can be used as a
+/// `fn(&str, &str) -> Ordering`.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use std::cmp::Ordering;
+///
+/// use uapi_version::strverscmp_as_ref;
+///
+/// assert_eq!(
+///     strverscmp_as_ref(Cow::Borrowed("1.10"), String::from("1.9")),
+///     Ordering::Greater
+/// );
+/// ```
+#[must_use]
+pub fn strverscmp_as_ref(a: impl AsRef<str>, b: impl AsRef<str>) -> Ordering {
+    strverscmp(a.as_ref(), b.as_ref())
+}
+
+/// Compare two byte strings as versions.
+///
+/// Behaves like [`strverscmp`] but accepts input that isn't valid UTF-8. All bytes outside of the
+/// ASCII range are invalid characters and thus ignored.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use uapi_version::strverscmp_bytes;
+///
+/// assert_eq!(strverscmp_bytes(b"1.\xff0", b"1.0"), Ordering::Equal)
+/// ```
+#[must_use]
+pub fn strverscmp_bytes(a: &[u8], b: &[u8]) -> Ordering {
+    compare(a, b, SeparatorPrecedence::UAPI, &CompareOptions::default())
+}
+
+/// Compare two version strings, rejecting digits that aren't ASCII.
+///
+/// [`strverscmp`] ignores digits from other scripts like any other invalid character, so `1٢`
+/// silently compares as equal to `1`. This makes it possible to craft versions that look newer
+/// than they are. Any character that Unicode considers numeric but that isn't an ASCII digit is
+/// rejected instead. That includes digits like `٢` as well as other numeric characters like `½`
+/// or `Ⅻ`. All other invalid characters are still ignored.
+///
+/// # Errors
+///
+/// Returns an error with the byte offset of the first such character. `a` is checked before `b`
+/// and the error doesn't say which of the two contains the character, so the offset refers to `b`
+/// only if `a` has no such character.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use uapi_version::{strverscmp_strict, StrictError};
+///
+/// assert_eq!(strverscmp_strict("12", "1"), Ok(Ordering::Greater));
+/// assert_eq!(
+///     strverscmp_strict("1\u{662}", "1"),
+///     Err(StrictError::NonAsciiDigit { index: 1 })
+/// );
+/// ```
+pub fn strverscmp_strict(a: &str, b: &str) -> Result<Ordering, StrictError> {
+    for s in [a, b] {
+        if let Some((index, _)) = s
+            .char_indices()
+            .find(|&(_, c)| c.is_numeric() && !c.is_ascii_digit())
+        {
+            return Err(StrictError::NonAsciiDigit { index });
+        }
+    }
+    Ok(strverscmp(a, b))
+}
+
+/// An error returned by [`strverscmp_strict`].
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum StrictError {
+    /// A digit outside of ASCII at the given byte offset.
+    ///
+    /// Other characters that Unicode considers numeric, like `½` or `Ⅻ`, are reported as well.
+    /// The offset is into the first input that contains such a character, see
+    /// [`strverscmp_strict`].
+    NonAsciiDigit { index: usize },
+}
+
+impl fmt::Display for StrictError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StrictError::NonAsciiDigit { index } => {
+                write!(f, "non-ASCII digit at byte offset {index}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for StrictError {}
+
+/// Compare two version strings and return the result as an `i8`.
+///
+/// Returns `-1`, `0`, or `1` if `a` is smaller than, equal to, or greater than `b` respectively.
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::strverscmp_i8;
+///
+/// assert_eq!(strverscmp_i8("1.0.0", "2.0.0"), -1)
+/// ```
+#[must_use]
+pub fn strverscmp_i8(a: &str, b: &str) -> i8 {
+    strverscmp(a, b) as i8
+}
+
+/// The rank of each kind of segment when two versions present different kinds at the same
+/// position.
+///
+/// The segment with the lower rank sorts first. Kinds that share a rank compare as equal and are
+/// skipped over on both sides. [`SeparatorPrecedence::UAPI`] (which is also the default)
+/// implements the order mandated by the specification: `~` < end of string < `-` < `^` < `.` <
+/// alphabetical < numerical.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct SeparatorPrecedence {
+    /// The pre-release separator `~`.
+    pub tilde: u8,
+    /// The end of the version string.
+    pub end: u8,
+    /// The release separator `-`.
+    pub dash: u8,
+    /// The patch separator `^`.
+    pub caret: u8,
+    /// The component separator `.`.
+    pub dot: u8,
+    /// The start of an alphabetical segment.
+    pub alpha: u8,
+    /// The start of a numerical segment.
+    pub numeric: u8,
+}
+
+impl SeparatorPrecedence {
+    /// The precedence described by the specification.
+    pub const UAPI: Self = Self {
+        tilde: 0,
+        end: 1,
+        dash: 2,
+        caret: 3,
+        dot: 4,
+        alpha: 5,
+        numeric: 6,
+    };
+
+    /// Return the rank of a token, `None` standing for the end of the version string.
+    fn rank(self, kind: Option<VersionTokenKind>) -> u8 {
+        match kind {
+            Some(VersionTokenKind::Tilde) => self.tilde,
+            None => self.end,
+            Some(VersionTokenKind::Dash) => self.dash,
+            Some(VersionTokenKind::Caret) => self.caret,
+            Some(VersionTokenKind::Dot) => self.dot,
+            Some(VersionTokenKind::Alpha) => self.alpha,
+            Some(VersionTokenKind::Numeric) => self.numeric,
+        }
+    }
+}
+
+impl Default for SeparatorPrecedence {
+    fn default() -> Self {
+        Self::UAPI
+    }
+}
+
+/// The separators to write for each of the separators of a version, used by
+/// [`Version::format_with`].
+///
+/// Each separator can be replaced by any string, including an empty one.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct SeparatorStyle<'a> {
+    /// Written for the pre-release separator `~`.
+    pub tilde: &'a str,
+    /// Written for the release separator `-`.
+    pub dash: &'a str,
+    /// Written for the patch separator `^`.
+    pub caret: &'a str,
+    /// Written for the component separator `.`.
+    pub dot: &'a str,
+}
+
+impl SeparatorStyle<'_> {
+    /// Keep all separators as they are.
+    pub const UAPI: Self = Self {
+        tilde: "~",
+        dash: "-",
+        caret: "^",
+        dot: ".",
+    };
+
+    /// Write `.` for `-` and `^`, but keep `~` as it makes a version older instead of newer.
+    pub const DOTTED: Self = Self {
+        dash: ".",
+        caret: ".",
+        ..Self::UAPI
+    };
+}
+
+impl Default for SeparatorStyle<'_> {
+    fn default() -> Self {
+        Self::UAPI
+    }
+}
+
+/// Return the shortest run of leading `.`-separated components that identifies each of
+/// `versions` within the list.
+///
+/// Components are compared with [`strverscmp`], just like in [`Version::common_prefix`]. If no
+/// prefix is unique, e.g. because the version appears twice or all of its components are shared
+/// with a longer version, the full version is returned. This takes quadratic time and is meant
+/// for display purposes.
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::{shortest_unique_prefixes, Version};
+///
+/// let versions = ["1.2.3", "1.2.4", "2.0"].map(Version::from);
+///
+/// assert_eq!(shortest_unique_prefixes(&versions), ["1.2.3", "1.2.4", "2"]);
+/// ```
+#[must_use]
+pub fn shortest_unique_prefixes(versions: &[Version]) -> Vec<String> {
+    versions
+        .iter()
+        .enumerate()
+        .map(|(i, version)| {
+            let shared = versions
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| i != j)
+                .map(|(_, other)| {
+                    version
+                        .split_components()
+                        .zip(other.split_components())
+                        .take_while(|(a, b)| strverscmp(a, b) == Ordering::Equal)
+                        .count()
+                })
+                .max()
+                .unwrap_or(0);
+            // Count a `.` after each component and drop the last one again.
+            let len: usize = version
+                .split_components()
+                .take(shared + 1)
+                .map(|c| c.len() + 1)
+                .sum();
+            version.0[..len - 1].to_string()
+        })
+        .collect()
+}
+
+/// Compare two version strings with a custom [`SeparatorPrecedence`].
+///
+/// With [`SeparatorPrecedence::UAPI`] this is the same as [`strverscmp`].
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use uapi_version::{strverscmp_with_precedence, SeparatorPrecedence};
+///
+/// let precedence = SeparatorPrecedence {
+///     caret: 0,
+///     tilde: 1,
+///     ..SeparatorPrecedence::UAPI
+/// };
+///
+/// assert_eq!(strverscmp_with_precedence("1^2", "1~2", &precedence), Ordering::Less)
+/// ```
+#[must_use]
+pub fn strverscmp_with_precedence(a: &str, b: &str, precedence: &SeparatorPrecedence) -> Ordering {
+    compare(
+        a.as_bytes(),
+        b.as_bytes(),
+        *precedence,
+        &CompareOptions::default(),
+    )
+}
+
+/// Options that change how versions are compared.
+///
+/// The default options implement the specification.
+#[derive(Clone, Copy, Default)]
+pub struct CompareOptions<'a> {
+    /// Break ties between numbers of equal value by their width: the one with fewer leading
+    /// zeros is greater, so `1.01` is smaller than `1.1`.
+    pub zero_width_tiebreak: bool,
+    /// Characters that separate components just like `.` does instead of being ignored. With
+    /// `_` in this list, `12_3` compares like `12.3`.
+    pub extra_separators: &'a [char],
+    /// Treat missing trailing components as zero, so `1.2` is equal to `1.2.0` and `1.2.0.0`.
+    /// Zero components are skipped at the end of a version and right before a `~`, `-` or `^`,
+    /// so `1.2.0~rc1` compares like `1.2~rc1` and is smaller than `1.2`, while `1.2` is still
+    /// smaller than `1.2.1`.
+    pub pad_trailing_zero: bool,
+    /// Treat `-` and `^` like `.`, so `1.0.5`, `1.0-5` and `1.0^5` are all equal. `~` keeps its
+    /// meaning because it sorts before the end of a version instead of after it.
+    pub unify_separators: bool,
+    /// Rank runs of letters with this function before comparing them bytewise. The run with the
+    /// lower rank is smaller, runs with equal ranks fall back to the byte order. This allows e.g.
+    /// sorting `dev` before `alpha` or `RC` after `beta`.
+    pub alpha_rank: Option<&'a dyn Fn(&str) -> i64>,
+}
+
+impl fmt::Debug for CompareOptions<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CompareOptions")
+            .field("zero_width_tiebreak", &self.zero_width_tiebreak)
+            .field("extra_separators", &self.extra_separators)
+            .field("pad_trailing_zero", &self.pad_trailing_zero)
+            .field("unify_separators", &self.unify_separators)
+            .field("alpha_rank", &self.alpha_rank.map(|_| format_args!("..")))
+            .finish()
+    }
+}
+
+/// Compare two version strings with the given [`CompareOptions`].
+///
+/// With the default options this is the same as [`strverscmp`].
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use uapi_version::{strverscmp_with, CompareOptions};
+///
+/// let options = CompareOptions {
+///     zero_width_tiebreak: true,
+///     ..CompareOptions::default()
+/// };
+///
+/// assert_eq!(strverscmp_with("1.01", "1.1", &options), Ordering::Less)
+/// ```
+#[must_use]
+pub fn strverscmp_with(a: &str, b: &str, options: &CompareOptions) -> Ordering {
+    compare(
+        a.as_bytes(),
+        b.as_bytes(),
+        SeparatorPrecedence::UAPI,
+        options,
+    )
+}
+
+/// Compare two versions for at most `max_segments` segments.
+///
+/// Returns `None` if the versions are equal for the first `max_segments` segments. A segment is a
+/// pair of tokens, one from each side, where the end of a version counts as a token. Deciding
+/// that `1.0` is equal to `1.0` thus takes four segments.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use uapi_version::strverscmp_with_budget;
+///
+/// assert_eq!(strverscmp_with_budget("1.2.3", "1.2.4", 5), Some(Ordering::Less));
+/// assert_eq!(strverscmp_with_budget("1.2.3", "1.2.4", 4), None);
+/// ```
+#[must_use]
+pub fn strverscmp_with_budget(a: &str, b: &str, max_segments: usize) -> Option<Ordering> {
+    compare_bounded(
+        Scanner::new(a.as_bytes()),
+        Scanner::new(b.as_bytes()),
+        SeparatorPrecedence::UAPI,
+        &CompareOptions::default(),
+        max_segments,
+        |_, _, _| {},
+    )
+}
+
+/// A step of the comparison as passed to the observer of [`strverscmp_observed`].
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct SegmentPair<'a> {
+    /// The token of the first version or `None` if it ended.
+    pub left: Option<VersionToken<'a>>,
+    /// The token of the second version or `None` if it ended.
+    pub right: Option<VersionToken<'a>>,
+    /// The ordering of the two tokens. The comparison stops at the first pair that isn't
+    /// [`Ordering::Equal`] or when both versions ended.
+    pub ordering: Ordering,
+}
+
+/// Compare two version strings like [`strverscmp`], calling `observer` for each compared pair of
+/// tokens.
+///
+/// The observer sees exactly the steps the comparison takes, the last one deciding the result.
+/// No memory is allocated besides what the observer itself allocates.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use uapi_version::strverscmp_observed;
+///
+/// let mut steps = Vec::new();
+/// let ordering = strverscmp_observed("1.2", "1.3", &mut |pair| {
+///     steps.push((pair.left.map(|t| t.as_str()), pair.right.map(|t| t.as_str())));
+/// });
+///
+/// assert_eq!(ordering, Ordering::Less);
+/// assert_eq!(
+///     steps,
+///     [(Some("1"), Some("1")), (Some("."), Some(".")), (Some("2"), Some("3"))]
+/// );
+/// ```
+pub fn strverscmp_observed<'a>(
+    a: &'a str,
+    b: &'a str,
+    observer: &mut impl FnMut(SegmentPair<'a>),
+) -> Ordering {
+    compare_bounded(
+        Scanner::new(a.as_bytes()),
+        Scanner::new(b.as_bytes()),
+        SeparatorPrecedence::UAPI,
+        &CompareOptions::default(),
+        usize::MAX,
+        |left, right, ordering| {
+            observer(SegmentPair {
+                left: left.map(|t| VersionToken::from_raw(a, t)),
+                right: right.map(|t| VersionToken::from_raw(b, t)),
+                ordering,
+            });
+        },
+    )
+    .unwrap_or(Ordering::Equal)
+}
+
+fn compare(
+    a: &[u8],
+    b: &[u8],
+    precedence: SeparatorPrecedence,
+    options: &CompareOptions,
+) -> Ordering {
+    compare_tokens_with(
+        Scanner::with_options(a, options),
+        Scanner::with_options(b, options),
+        precedence,
+        options,
+    )
+}
+
+/// Compare two versions given as their tokens.
+fn compare_tokens_with<'a, 'b>(
+    left: impl Iterator<Item = RawToken<'a>> + Clone,
+    right: impl Iterator<Item = RawToken<'b>> + Clone,
+    precedence: SeparatorPrecedence,
+    options: &CompareOptions,
+) -> Ordering {
+    compare_bounded(left, right, precedence, options, usize::MAX, |_, _, _| {})
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Compare two versions given as their tokens, returning `None` if they're equal for the first
+/// `max_segments` segments.
+///
+/// `observe` is called with each pair of tokens and the ordering they result in.
+fn compare_bounded<'a, 'b>(
+    mut left_tokens: impl Iterator<Item = RawToken<'a>> + Clone,
+    mut right_tokens: impl Iterator<Item = RawToken<'b>> + Clone,
+    precedence: SeparatorPrecedence,
+    options: &CompareOptions,
+    max_segments: usize,
+    mut observe: impl FnMut(Option<RawToken<'a>>, Option<RawToken<'b>>, Ordering),
+) -> Option<Ordering> {
+    for _ in 0..max_segments {
+        let left = next_token(&mut left_tokens, options.pad_trailing_zero);
+        let right = next_token(&mut right_tokens, options.pad_trailing_zero);
+
+        let ordering = precedence
+            .rank(left.map(|t| t.kind))
+            .cmp(&precedence.rank(right.map(|t| t.kind)))
+            .then_with(|| match (left, right) {
+                (Some(left), Some(right)) => compare_tokens(left, right, options),
+                _ => Ordering::Equal,
+            });
+        observe(left, right, ordering);
+        if ordering != Ordering::Equal || (left.is_none() && right.is_none()) {
+            return Some(ordering);
+        }
+    }
+    None
+}
+
+/// Compare two tokens of the same rank.
+fn compare_tokens(left: RawToken, right: RawToken, options: &CompareOptions) -> Ordering {
+    match (left.kind, right.kind) {
+        (VersionTokenKind::Numeric, VersionTokenKind::Numeric) => {
+            let ordering = compare_numeric(left.bytes, right.bytes);
+            if options.zero_width_tiebreak {
+                ordering.then_with(|| right.bytes.len().cmp(&left.bytes.len()))
+            } else {
+                ordering
+            }
+        }
+        (VersionTokenKind::Alpha, VersionTokenKind::Alpha) => {
+            let ranking = options.alpha_rank.map_or(Ordering::Equal, |rank| {
+                // Runs of letters only ever consist of ASCII characters.
+                let as_str = |bytes| core::str::from_utf8(bytes).unwrap_or_default();
+                rank(as_str(left.bytes)).cmp(&rank(as_str(right.bytes)))
+            });
+            ranking.then_with(|| left.bytes.cmp(right.bytes))
+        }
+        _ => Ordering::Equal,
+    }
+}
+
+/// Return the next token, skipping zero components that are followed by the end of the version or
+/// by a `~`, `-` or `^` if `pad` is set.
+fn next_token<'a>(
+    tokens: &mut (impl Iterator<Item = RawToken<'a>> + Clone),
+    pad: bool,
+) -> Option<RawToken<'a>> {
+    let token = tokens.next()?;
+    if !pad || token.kind != VersionTokenKind::Dot {
+        return Some(token);
+    }
+    let mut rest = tokens.clone();
+    loop {
+        match rest.next() {
+            Some(zero)
+                if zero.kind == VersionTokenKind::Numeric
+                    && zero.bytes.iter().all(|&b| b == b'0') => {}
+            _ => return Some(token),
+        }
+        let mut after = rest.clone();
+        match after.next() {
+            Some(next) if next.kind == VersionTokenKind::Dot => rest = after,
+            next @ (None
+            | Some(RawToken {
+                kind: VersionTokenKind::Tilde | VersionTokenKind::Dash | VersionTokenKind::Caret,
+                ..
+            })) => {
+                *tokens = after;
+                return next;
+            }
+            Some(_) => return Some(token),
+        }
+    }
+}
+
+/// Compare two runs of digits by their numerical value.
+fn compare_numeric(left: &[u8], right: &[u8]) -> Ordering {
+    let left = strip_leading_zeros(left);
+    let right = strip_leading_zeros(right);
+    // Longer numbers are bigger, numbers of the same length compare like strings.
+    left.len().cmp(&right.len()).then_with(|| left.cmp(right))
+}
+
+/// Strip leading zeros, keeping a single `0` if all digits are zero.
+fn strip_leading_zeros(mut digits: &[u8]) -> &[u8] {
+    while digits.len() > 1 && digits[0] == b'0' {
+        digits = &digits[1..];
+    }
+    digits
+}
+
+/// The direction in which versions are ordered.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum Direction {
+    /// Oldest first.
+    #[default]
+    Ascending,
+    /// Newest first.
+    Descending,
+}
+
+/// Compare two version strings in the given [`Direction`].
+///
+/// [`Direction::Ascending`] is the same as [`strverscmp`], [`Direction::Descending`] reverses the
+/// result.
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::{strverscmp_ordered, Direction};
+///
+/// let mut versions = ["1.0", "3.0", "2.0"];
+///
+/// versions.sort_by(|a, b| strverscmp_ordered(a, b, Direction::Descending));
+///
+/// assert_eq!(versions, ["3.0", "2.0", "1.0"])
+/// ```
+#[must_use]
+pub fn strverscmp_ordered(a: &str, b: &str, direction: Direction) -> Ordering {
+    let ordering = strverscmp(a, b);
+    match direction {
+        Direction::Ascending => ordering,
+        Direction::Descending => ordering.reverse(),
+    }
+}
+
+/// A [`Version`] that orders from newest to oldest.
+///
+/// This is the same as [`core::cmp::Reverse`] but reads better as the element type of a
+/// [`BinaryHeap`](alloc::collections::BinaryHeap) that should yield the oldest version first.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BinaryHeap;
+///
+/// use uapi_version::{OldestFirst, Version};
+///
+/// let mut heap: BinaryHeap<_> = ["1.10", "1.9", "2.0"]
+///     .map(|v| OldestFirst(Version::from(v)))
+///     .into();
+///
+/// assert_eq!(heap.pop(), Some(OldestFirst(Version::from("1.9"))));
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct OldestFirst(pub Version);
+
+impl PartialOrd for OldestFirst {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OldestFirst {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+/// Return the index of the first `.`-separated component in which `a` and `b` differ.
+///
+/// Components are compared with [`strverscmp`]. A component that only one side has counts as a
+/// difference. Returns `None` if all components compare as equal.
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::diverging_component;
+///
+/// assert_eq!(diverging_component("1.2.3", "2.0.0"), Some(0));
+/// assert_eq!(diverging_component("1.2.3", "1.2.4"), Some(2));
+/// assert_eq!(diverging_component("1.2", "1.2.0"), Some(2));
+/// assert_eq!(diverging_component("1.2", "1.02"), None);
+/// ```
+#[must_use]
+pub fn diverging_component(a: &str, b: &str) -> Option<usize> {
+    let mut a = a.split('.');
+    let mut b = b.split('.');
+    (0..).find_map(|i| match (a.next(), b.next()) {
+        (None, None) => Some(None),
+        (Some(a), Some(b)) if strverscmp(a, b) == Ordering::Equal => None,
+        _ => Some(Some(i)),
+    })?
+}
+
+fn is_valid_version_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '~' | '-' | '^' | '.')
+}
+
+fn leading_digits(s: &str) -> &str {
+    let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    &s[..end]
+}
+
+/// Iterate over the leading run of `.`-separated numbers, e.g. `1` and `2` for `1.2rc1-3`.
+fn numeric_core(s: &str) -> impl Iterator<Item = &str> {
+    let mut done = false;
+    s.split('.').map_while(move |component| {
+        let digits = leading_digits(component);
+        if done || digits.is_empty() {
+            return None;
+        }
+        done = digits.len() != component.len();
+        Some(digits)
+    })
+}
+
+/// Parse a string consisting only of ASCII digits.
+fn parse_digits(s: &str) -> Option<u64> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+/// Parse a run of ASCII digits, saturating at `u64::MAX`.
+fn saturating_parse(digits: &str) -> u64 {
+    digits.parse().unwrap_or(u64::MAX)
+}
+
+/// Return the number of days of `month` or `None` if it isn't a valid month.
+fn days_in_month(year: u16, month: u8) -> Option<u8> {
+    let is_leap_year =
+        year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+    match month {
+        2 if is_leap_year => Some(29),
+        2 => Some(28),
+        4 | 6 | 9 | 11 => Some(30),
+        1..=12 => Some(31),
+        _ => None,
+    }
 }
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use crate::{NormalizedVersion, Version};
+
+/// A map keyed by versions that compare as equal.
+///
+/// Entries are looked up by the [`NormalizedVersion`] of their key, so `0_` and `0` refer to the
+/// same entry.
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::{Version, VersionMap};
+///
+/// let mut map = VersionMap::new();
+/// map.insert(Version::from("1.0_"), "first");
+///
+/// assert_eq!(map.get(&Version::from("1.0")), Some(&"first"));
+/// assert_eq!(map.get_str("01.00"), Some(&"first"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct VersionMap<V> {
+    entries: HashMap<NormalizedVersion, (Version, V)>,
+}
+
+impl<V> VersionMap<V> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Insert a value, returning the previous value of an equal version.
+    ///
+    /// The key replaces the previous key as well.
+    pub fn insert(&mut self, key: Version, value: V) -> Option<V> {
+        self.entries
+            .insert(key.normalized(), (key, value))
+            .map(|(_, value)| value)
+    }
+
+    #[must_use]
+    pub fn get(&self, key: &Version) -> Option<&V> {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    /// Return the stored key, which may differ from `key` in its string, and its value.
+    #[must_use]
+    pub fn get_key_value(&self, key: &Version) -> Option<(&Version, &V)> {
+        self.entries
+            .get(&key.normalized())
+            .map(|(key, value)| (key, value))
+    }
+
+    #[must_use]
+    pub fn get_str(&self, key: &str) -> Option<&V> {
+        self.get(&Version::from(key))
+    }
+
+    pub fn remove(&mut self, key: &Version) -> Option<V> {
+        self.entries
+            .remove(&key.normalized())
+            .map(|(_, value)| value)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<V> Default for VersionMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
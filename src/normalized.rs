@@ -0,0 +1,90 @@
+use alloc::fmt;
+use alloc::string::String;
+use core::cmp::Ordering;
+
+use crate::token::Scanner;
+use crate::{strip_leading_zeros, strverscmp, Version, VersionTokenKind};
+
+/// A [`Version`] in canonical form.
+///
+/// Created by [`Version::normalized`]. The canonical form only keeps what matters for the
+/// comparison: invalid characters are dropped and numerical runs lose their leading zeros. Where
+/// dropping invalid characters would merge two runs of the same kind, a single `_` is kept in
+/// between, so `1_2` stays `1_2` while `1_a` becomes `1a`.
+///
+/// The canonical form is not order-preserving under plain string comparison (`10` sorts before
+/// `9` bytewise), so [`Ord`] still uses [`strverscmp`]. However, two versions compare as
+/// [`Ordering::Equal`] if and only if their canonical forms are identical. Unlike for
+/// [`Version`], `==` and [`Ord`] thus always agree, which also makes `NormalizedVersion` suitable
+/// as a key of hash based collections.
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::Version;
+///
+/// let version = Version::from("01.002~rc_1");
+///
+/// assert_eq!(version.normalized().as_str(), "1.2~rc1");
+/// assert_eq!(version.normalized(), Version::from("1.2~rc1").normalized());
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+pub struct NormalizedVersion(String);
+
+impl NormalizedVersion {
+    pub(crate) fn new(version: &Version) -> Self {
+        let mut s = String::with_capacity(version.as_str().len());
+        write_normalized(version, &mut s);
+        Self(s)
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    #[must_use]
+    pub fn into_version(self) -> Version {
+        Version::from(self.0)
+    }
+}
+
+impl fmt::Display for NormalizedVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl PartialOrd for NormalizedVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NormalizedVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        strverscmp(&self.0, &other.0)
+    }
+}
+
+/// Append the canonical form of `version` to `buf`.
+pub(crate) fn write_normalized(version: &Version, buf: &mut String) {
+    let mut previous = None;
+    let mut scanner = Scanner::new(version.as_bytes());
+    while let Some(token) = scanner.next_token() {
+        let is_run = matches!(
+            token.kind,
+            VersionTokenKind::Alpha | VersionTokenKind::Numeric
+        );
+        if is_run && previous == Some(token.kind) {
+            buf.push('_');
+        }
+        let bytes = match token.kind {
+            VersionTokenKind::Numeric => strip_leading_zeros(token.bytes),
+            _ => token.bytes,
+        };
+        // Tokens only ever consist of ASCII characters.
+        buf.extend(bytes.iter().copied().map(char::from));
+        previous = Some(token.kind);
+    }
+}
@@ -0,0 +1,123 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::Range;
+
+use crate::token::{RawToken, Scanner};
+use crate::{compare_tokens_with, CompareOptions, SeparatorPrecedence, Version, VersionTokenKind};
+
+/// A [`Version`] together with its tokens.
+///
+/// Comparing a `Version` splits it into tokens every time. When the same versions are compared
+/// over and over again, e.g. while sorting, `ParsedVersion` avoids that by tokenizing once up
+/// front. It orders exactly like the `Version` it was created from and can also be compared with
+/// a plain `Version` directly. Like for `Version`, `==` compares the underlying strings.
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::{ParsedVersion, Version};
+///
+/// let parsed = ParsedVersion::from(Version::from("1.0~rc1"));
+///
+/// assert!(parsed < Version::from("1.0"));
+/// assert!(Version::from("0.9") < parsed);
+/// assert_eq!(parsed, Version::from("1.0~rc1"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParsedVersion {
+    version: Version,
+    segments: Vec<(Range<usize>, VersionTokenKind)>,
+}
+
+impl ParsedVersion {
+    #[must_use]
+    pub fn new(version: Version) -> Self {
+        let segments = version.segment_spans();
+        Self { version, segments }
+    }
+
+    #[must_use]
+    pub fn as_version(&self) -> &Version {
+        &self.version
+    }
+
+    #[must_use]
+    pub fn into_version(self) -> Version {
+        self.version
+    }
+
+    fn raw_tokens(&self) -> impl Iterator<Item = RawToken<'_>> + Clone {
+        let bytes = self.version.as_bytes();
+        self.segments.iter().map(|(range, kind)| RawToken {
+            kind: *kind,
+            bytes: &bytes[range.clone()],
+            start: range.start,
+        })
+    }
+}
+
+impl From<Version> for ParsedVersion {
+    fn from(version: Version) -> Self {
+        Self::new(version)
+    }
+}
+
+impl From<&str> for ParsedVersion {
+    fn from(s: &str) -> Self {
+        Self::new(Version::from(s))
+    }
+}
+
+impl PartialEq for ParsedVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.version == other.version
+    }
+}
+
+impl Eq for ParsedVersion {}
+
+impl PartialOrd for ParsedVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ParsedVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_tokens_with(
+            self.raw_tokens(),
+            other.raw_tokens(),
+            SeparatorPrecedence::UAPI,
+            &CompareOptions::default(),
+        )
+    }
+}
+
+impl PartialEq<Version> for ParsedVersion {
+    fn eq(&self, other: &Version) -> bool {
+        self.version == *other
+    }
+}
+
+impl PartialEq<ParsedVersion> for Version {
+    fn eq(&self, other: &ParsedVersion) -> bool {
+        *self == other.version
+    }
+}
+
+impl PartialOrd<Version> for ParsedVersion {
+    fn partial_cmp(&self, other: &Version) -> Option<Ordering> {
+        Some(compare_tokens_with(
+            self.raw_tokens(),
+            Scanner::new(other.as_bytes()),
+            SeparatorPrecedence::UAPI,
+            &CompareOptions::default(),
+        ))
+    }
+}
+
+impl PartialOrd<ParsedVersion> for Version {
+    fn partial_cmp(&self, other: &ParsedVersion) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
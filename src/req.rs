@@ -0,0 +1,269 @@
+use alloc::fmt;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::str::FromStr;
+
+use crate::{numeric_core, strverscmp, Version};
+
+/// A requirement that versions can be matched against.
+///
+/// A requirement is a comma-separated list of comparators. A version matches the requirement if
+/// it matches every comparator. Each comparator consists of an optional operator followed by a
+/// version. Without an operator, the version has to match exactly.
+///
+/// | Operator | Matches                                                            |
+/// |----------|--------------------------------------------------------------------|
+/// | `=`      | versions that compare as equal                                     |
+/// | `>`      | newer versions                                                     |
+/// | `>=`     | equal or newer versions                                            |
+/// | `<`      | older versions                                                     |
+/// | `<=`     | equal or older versions                                            |
+/// | `^`      | equal or newer versions with the same first numeric component      |
+/// | `~`      | equal or newer versions with the same first two numeric components |
+///
+/// The numeric components are the leading run of `.`-separated numbers, e.g. `1` and `2` for
+/// `1.2rc1-3`. If the version in a `~` comparator has only a single numeric component, only that
+/// one has to be equal.
+///
+/// `^` and `~` are also valid characters in a version. When they appear at the start of a
+/// comparator, they are always parsed as the operator. Use `=` to match a version that starts
+/// with one of them, e.g. `=~rc1`.
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::{Version, VersionReq};
+///
+/// let req = VersionReq::parse("^1.2").unwrap();
+///
+/// assert!(req.matches(&Version::from("1.9")));
+/// assert!(!req.matches(&Version::from("2.0")));
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct VersionReq {
+    comparators: Vec<Comparator>,
+}
+
+/// A single comparator of a [`VersionReq`].
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Comparator {
+    pub op: ReqOp,
+    pub version: Version,
+}
+
+/// The operator of a [`Comparator`].
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum ReqOp {
+    /// `=`
+    Exact,
+    /// `>`
+    Greater,
+    /// `>=`
+    GreaterEq,
+    /// `<`
+    Less,
+    /// `<=`
+    LessEq,
+    /// `^`
+    Caret,
+    /// `~`
+    Tilde,
+}
+
+/// An error returned when parsing a [`VersionReq`] fails.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum ParseReqError {
+    /// A comparator is empty, e.g. in `">=1.0,"`.
+    EmptyComparator,
+    /// An operator isn't followed by a version.
+    MissingVersion,
+    /// The version of a `^` or `~` comparator doesn't start with a number.
+    MissingNumericComponent,
+}
+
+impl VersionReq {
+    /// Parse a requirement.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any comparator is empty, lacks a version, or is a `^` or `~`
+    /// comparator whose version doesn't start with a number.
+    pub fn parse(s: &str) -> Result<Self, ParseReqError> {
+        let comparators = s
+            .split(',')
+            .map(Comparator::parse)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { comparators })
+    }
+
+    /// Check whether a version matches every comparator.
+    #[must_use]
+    pub fn matches(&self, version: &Version) -> bool {
+        self.comparators.iter().all(|c| c.matches(version))
+    }
+
+    #[must_use]
+    pub fn comparators(&self) -> &[Comparator] {
+        &self.comparators
+    }
+}
+
+/// Return the newest version that matches `req`.
+///
+/// If several of the newest matching versions compare as equal, the first one is returned.
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::{latest_matching, Version, VersionReq};
+///
+/// let versions = ["1.0", "1.5", "2.0"].map(Version::from);
+/// let req = VersionReq::parse("<2.0").unwrap();
+///
+/// assert_eq!(latest_matching(&versions, &req), Some(&Version::from("1.5")));
+/// ```
+#[must_use]
+pub fn latest_matching<'a>(versions: &'a [Version], req: &VersionReq) -> Option<&'a Version> {
+    versions
+        .iter()
+        .filter(|v| req.matches(v))
+        .reduce(|newest, v| if v > newest { v } else { newest })
+}
+
+impl Comparator {
+    fn parse(s: &str) -> Result<Self, ParseReqError> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseReqError::EmptyComparator);
+        }
+
+        let (op, rest) = [
+            (">=", ReqOp::GreaterEq),
+            ("<=", ReqOp::LessEq),
+            (">", ReqOp::Greater),
+            ("<", ReqOp::Less),
+            ("=", ReqOp::Exact),
+            ("^", ReqOp::Caret),
+            ("~", ReqOp::Tilde),
+        ]
+        .into_iter()
+        .find_map(|(symbol, op)| s.strip_prefix(symbol).map(|rest| (op, rest)))
+        .unwrap_or((ReqOp::Exact, s));
+
+        let rest = rest.trim();
+        if rest.is_empty() {
+            return Err(ParseReqError::MissingVersion);
+        }
+        if matches!(op, ReqOp::Caret | ReqOp::Tilde) && numeric_core(rest).next().is_none() {
+            return Err(ParseReqError::MissingNumericComponent);
+        }
+
+        Ok(Self {
+            op,
+            version: Version::from(rest),
+        })
+    }
+
+    /// Check whether a version matches the comparator.
+    #[must_use]
+    pub fn matches(&self, version: &Version) -> bool {
+        let ordering = version.cmp(&self.version);
+        match self.op {
+            ReqOp::Exact => ordering == Ordering::Equal,
+            ReqOp::Greater => ordering == Ordering::Greater,
+            ReqOp::GreaterEq => ordering != Ordering::Less,
+            ReqOp::Less => ordering == Ordering::Less,
+            ReqOp::LessEq => ordering != Ordering::Greater,
+            ReqOp::Caret => ordering != Ordering::Less && self.same_numeric_prefix(version, 1),
+            ReqOp::Tilde => ordering != Ordering::Less && self.same_numeric_prefix(version, 2),
+        }
+    }
+
+    /// Check whether the first `depth` numeric components of `version` are equal to the ones of
+    /// the comparator's version.
+    fn same_numeric_prefix(&self, version: &Version, depth: usize) -> bool {
+        let mut theirs = numeric_core(version.as_str());
+        numeric_core(self.version.as_str()).take(depth).all(|ours| {
+            theirs
+                .next()
+                .is_some_and(|t| strverscmp(ours, t) == Ordering::Equal)
+        })
+    }
+}
+
+impl From<Comparator> for VersionReq {
+    fn from(comparator: Comparator) -> Self {
+        Self {
+            comparators: vec![comparator],
+        }
+    }
+}
+
+/// Parse a requirement with [`VersionReq::parse`].
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::{Version, VersionReq};
+///
+/// let req: VersionReq = ">=1.2, <2.0".parse().unwrap();
+///
+/// assert!(req.matches(&Version::from("1.5")));
+/// assert!(!req.matches(&Version::from("2.0")));
+/// ```
+impl FromStr for VersionReq {
+    type Err = ParseReqError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for VersionReq {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, comparator) in self.comparators.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{comparator}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Comparator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.op, self.version)
+    }
+}
+
+impl fmt::Display for ReqOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let symbol = match self {
+            ReqOp::Exact => "=",
+            ReqOp::Greater => ">",
+            ReqOp::GreaterEq => ">=",
+            ReqOp::Less => "<",
+            ReqOp::LessEq => "<=",
+            ReqOp::Caret => "^",
+            ReqOp::Tilde => "~",
+        };
+        write!(f, "{symbol}")
+    }
+}
+
+impl fmt::Display for ParseReqError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            ParseReqError::EmptyComparator => "empty comparator",
+            ParseReqError::MissingVersion => "operator is not followed by a version",
+            ParseReqError::MissingNumericComponent => {
+                "version of a caret or tilde comparator does not start with a number"
+            }
+        };
+        write!(f, "{message}")
+    }
+}
+
+impl core::error::Error for ParseReqError {}
@@ -0,0 +1,110 @@
+use alloc::string::String;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Version;
+
+impl Serialize for Version {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Version {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Version::from)
+    }
+}
+
+/// Serialize a [`Version`] as a struct with its parts as separate fields.
+///
+/// Use it with `#[serde(with = "uapi_version::structured")]`. The fields are `upstream` and the
+/// optional `prerelease`, `patch` and `release`, see [`Version::upstream`]. Missing parts are
+/// skipped when serializing and may be omitted when deserializing.
+///
+/// # Examples
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use uapi_version::Version;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Package {
+///     #[serde(with = "uapi_version::structured")]
+///     version: Version,
+/// }
+///
+/// let package = Package { version: Version::from("1.0~rc1-5") };
+/// let json = serde_json::to_string(&package).unwrap();
+///
+/// assert_eq!(
+///     json,
+///     r#"{"version":{"upstream":"1.0","prerelease":"rc1","release":"5"}}"#
+/// );
+/// assert_eq!(serde_json::from_str::<Package>(&json).unwrap().version, package.version);
+/// ```
+pub mod structured {
+    use alloc::string::String;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::Version;
+
+    #[derive(Serialize)]
+    struct Fields<'a> {
+        upstream: &'a str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        prerelease: Option<&'a str>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        patch: Option<&'a str>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        release: Option<&'a str>,
+    }
+
+    #[derive(Deserialize)]
+    struct OwnedFields {
+        upstream: String,
+        #[serde(default)]
+        prerelease: Option<String>,
+        #[serde(default)]
+        patch: Option<String>,
+        #[serde(default)]
+        release: Option<String>,
+    }
+
+    /// Serialize `version` as a struct.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails.
+    pub fn serialize<S: Serializer>(version: &Version, serializer: S) -> Result<S::Ok, S::Error> {
+        Fields {
+            upstream: version.upstream(),
+            prerelease: version.prerelease_tag(),
+            patch: version.patch(),
+            release: version.release(),
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserialize a version from a struct.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input isn't a struct with the expected fields.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Version, D::Error> {
+        let fields = OwnedFields::deserialize(deserializer)?;
+        let mut s = fields.upstream;
+        for (separator, part) in [
+            ('~', fields.prerelease),
+            ('^', fields.patch),
+            ('-', fields.release),
+        ] {
+            if let Some(part) = part {
+                s.push(separator);
+                s.push_str(&part);
+            }
+        }
+        Ok(Version::from(s))
+    }
+}
@@ -0,0 +1,49 @@
+//! Scan runs of ASCII digits and letters eight bytes at a time.
+//!
+//! This uses plain `u64` arithmetic ("SIMD within a register") and thus works on every target,
+//! including `no_std` ones, without any `unsafe` code.
+
+const LANES: usize = 8;
+
+const HIGH: u64 = splat(0x80);
+const LOW: u64 = splat(0x7f);
+
+const fn splat(byte: u8) -> u64 {
+    u64::from_ne_bytes([byte; LANES])
+}
+
+/// Set the high bit of every byte of `word` that is an ASCII digit.
+pub(crate) fn digit_mask(word: u64) -> u64 {
+    let low = word & LOW;
+    // The high bit of each byte is set iff byte >= '0' and byte <= '9' respectively. Masking the
+    // input to seven bits first means that no addition can carry into the next byte.
+    let at_least_zero = low + splat(0x80 - b'0');
+    let above_nine = low + splat(0x80 - (b'9' + 1));
+    at_least_zero & !above_nine & !word & HIGH
+}
+
+/// Set the high bit of every byte of `word` that is an ASCII letter.
+pub(crate) fn alpha_mask(word: u64) -> u64 {
+    // Setting bit 5 maps upper case to lower case letters and no other byte into 'a'..='z'.
+    let lower = (word & LOW) | splat(0x20);
+    let at_least_a = lower + splat(0x80 - b'a');
+    let above_z = lower + splat(0x80 - (b'z' + 1));
+    at_least_a & !above_z & !word & HIGH
+}
+
+/// Advance `pos` over all full words in which every byte matches `mask`.
+///
+/// The returned position is the start of the first word containing a non-matching byte or of the
+/// trailing bytes that don't fill a whole word. The caller is expected to scan the rest byte by
+/// byte.
+pub(crate) fn skip_words(bytes: &[u8], mut pos: usize, mask: fn(u64) -> u64) -> usize {
+    for chunk in bytes[pos..].chunks_exact(LANES) {
+        let mut word = [0; LANES];
+        word.copy_from_slice(chunk);
+        if mask(u64::from_ne_bytes(word)) != HIGH {
+            break;
+        }
+        pos += LANES;
+    }
+    pos
+}
@@ -0,0 +1,115 @@
+use alloc::fmt;
+use core::cmp::Ordering;
+
+use crate::Version;
+
+/// A version as given on a command line, which can also be one of the keywords `latest` and
+/// `stable`.
+///
+/// Both keywords sort above every concrete version, with `latest` sorting above `stable`.
+/// [`SymbolicVersion::resolve`] turns them into one of a list of available versions: `latest`
+/// resolves to the newest one, `stable` to the newest one that is not a pre-release. Concrete
+/// versions compare via [`strverscmp`](crate::strverscmp).
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::{SymbolicVersion, Version};
+///
+/// let latest = SymbolicVersion::from("Latest");
+///
+/// assert_eq!(latest, SymbolicVersion::Latest);
+/// assert!(latest > SymbolicVersion::from("999"));
+/// assert_eq!(
+///     SymbolicVersion::from("1.0"),
+///     SymbolicVersion::Concrete(Version::from("1.0"))
+/// );
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum SymbolicVersion {
+    Concrete(Version),
+    /// The newest version that is not a pre-release.
+    Stable,
+    /// The newest version.
+    Latest,
+}
+
+impl SymbolicVersion {
+    /// Pick the version from `available` that this stands for.
+    ///
+    /// A concrete version resolves to the first of `available` that compares as equal to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uapi_version::{SymbolicVersion, Version};
+    ///
+    /// let available = ["1.0", "2.0~rc1", "1.5"].map(Version::from);
+    ///
+    /// assert_eq!(SymbolicVersion::Latest.resolve(&available), Some(&available[1]));
+    /// assert_eq!(SymbolicVersion::Stable.resolve(&available), Some(&available[2]));
+    /// ```
+    #[must_use]
+    pub fn resolve<'a>(&self, available: &'a [Version]) -> Option<&'a Version> {
+        let newest = |versions: &mut dyn Iterator<Item = &'a Version>| {
+            versions.reduce(|newest, v| if v > newest { v } else { newest })
+        };
+        match self {
+            Self::Concrete(version) => available.iter().find(|v| v.is_equivalent(version)),
+            Self::Stable => newest(&mut available.iter().filter(|v| !v.is_prerelease())),
+            Self::Latest => newest(&mut available.iter()),
+        }
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Concrete(_) => 0,
+            Self::Stable => 1,
+            Self::Latest => 2,
+        }
+    }
+}
+
+/// Parse a symbolic version, recognizing the keywords case-insensitively.
+impl From<&str> for SymbolicVersion {
+    fn from(s: &str) -> Self {
+        if s.eq_ignore_ascii_case("latest") {
+            Self::Latest
+        } else if s.eq_ignore_ascii_case("stable") {
+            Self::Stable
+        } else {
+            Self::Concrete(Version::from(s))
+        }
+    }
+}
+
+impl From<Version> for SymbolicVersion {
+    fn from(version: Version) -> Self {
+        Self::Concrete(version)
+    }
+}
+
+impl fmt::Display for SymbolicVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Concrete(version) => write!(f, "{version}"),
+            Self::Stable => write!(f, "stable"),
+            Self::Latest => write!(f, "latest"),
+        }
+    }
+}
+
+impl PartialOrd for SymbolicVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SymbolicVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Concrete(a), Self::Concrete(b)) => a.cmp(b),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
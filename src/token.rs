@@ -0,0 +1,191 @@
+use core::iter::FusedIterator;
+
+use crate::{is_valid_version_char, CompareOptions};
+
+/// The kind of a [`VersionToken`].
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum VersionTokenKind {
+    /// The pre-release separator `~`.
+    Tilde,
+    /// The release separator `-`.
+    Dash,
+    /// The patch separator `^`.
+    Caret,
+    /// The component separator `.`.
+    Dot,
+    /// A run of ASCII letters.
+    Alpha,
+    /// A run of ASCII digits.
+    Numeric,
+}
+
+/// A single token of a version string.
+///
+/// Separators are always a single character long, alphabetical and numerical tokens are maximal
+/// runs of ASCII letters and digits respectively.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct VersionToken<'a> {
+    kind: VersionTokenKind,
+    text: &'a str,
+}
+
+impl<'a> VersionToken<'a> {
+    /// Create a token from one that the [`Scanner`] found in `s`.
+    pub(crate) fn from_raw(s: &'a str, raw: RawToken) -> Self {
+        Self {
+            kind: raw.kind,
+            text: &s[raw.start..raw.start + raw.bytes.len()],
+        }
+    }
+
+    #[must_use]
+    pub fn kind(&self) -> VersionTokenKind {
+        self.kind
+    }
+
+    /// Return the text of the token as it appears in the version, including leading zeros.
+    #[must_use]
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+}
+
+/// An iterator over the tokens of a version.
+///
+/// Created by [`Version::tokens`](crate::Version::tokens).
+#[derive(Debug, Clone)]
+pub struct Tokens<'a> {
+    s: &'a str,
+    scanner: Scanner<'a>,
+}
+
+impl<'a> Tokens<'a> {
+    pub(crate) fn new(s: &'a str) -> Self {
+        Self {
+            s,
+            scanner: Scanner::new(s.as_bytes()),
+        }
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = VersionToken<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let raw = self.scanner.next_token()?;
+        Some(VersionToken::from_raw(self.s, raw))
+    }
+}
+
+impl FusedIterator for Tokens<'_> {}
+
+/// A token as produced by the [`Scanner`].
+#[derive(Clone, Copy)]
+pub(crate) struct RawToken<'a> {
+    pub(crate) kind: VersionTokenKind,
+    pub(crate) bytes: &'a [u8],
+    pub(crate) start: usize,
+}
+
+/// Splits a version string into tokens, skipping invalid characters.
+///
+/// Works on bytes so that it can also be used on input that isn't valid UTF-8. Non-ASCII bytes
+/// are never valid, so multi-byte characters are skipped entirely.
+#[derive(Debug, Clone)]
+pub(crate) struct Scanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    extra_separators: &'a [char],
+    unify_separators: bool,
+}
+
+impl<'a> Scanner<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self::with_options(bytes, &CompareOptions::default())
+    }
+
+    /// Create a scanner that emits [`VersionTokenKind::Dot`] for each of the extra separators
+    /// and, if separators are unified, also for `-` and `^`.
+    pub(crate) fn with_options(bytes: &'a [u8], options: &CompareOptions<'a>) -> Self {
+        Self {
+            bytes,
+            pos: 0,
+            extra_separators: options.extra_separators,
+            unify_separators: options.unify_separators,
+        }
+    }
+
+    pub(crate) fn next_token(&mut self) -> Option<RawToken<'a>> {
+        let start = loop {
+            let byte = *self.bytes.get(self.pos)?;
+            if let Some(len) = self.extra_separator_len() {
+                let start = self.pos;
+                self.pos += len;
+                return Some(RawToken {
+                    kind: VersionTokenKind::Dot,
+                    bytes: &self.bytes[start..self.pos],
+                    start,
+                });
+            }
+            if is_valid_version_char(char::from(byte)) {
+                break self.pos;
+            }
+            self.pos += 1;
+        };
+        let byte = self.bytes[start];
+
+        let kind = if byte.is_ascii_digit() {
+            #[cfg(feature = "simd")]
+            {
+                self.pos = crate::simd::skip_words(self.bytes, self.pos, crate::simd::digit_mask);
+            }
+            self.skip_run(u8::is_ascii_digit);
+            VersionTokenKind::Numeric
+        } else if byte.is_ascii_alphabetic() {
+            #[cfg(feature = "simd")]
+            {
+                self.pos = crate::simd::skip_words(self.bytes, self.pos, crate::simd::alpha_mask);
+            }
+            self.skip_run(u8::is_ascii_alphabetic);
+            VersionTokenKind::Alpha
+        } else {
+            self.pos += 1;
+            match byte {
+                b'~' => VersionTokenKind::Tilde,
+                b'-' if !self.unify_separators => VersionTokenKind::Dash,
+                b'^' if !self.unify_separators => VersionTokenKind::Caret,
+                _ => VersionTokenKind::Dot,
+            }
+        };
+
+        Some(RawToken {
+            kind,
+            bytes: &self.bytes[start..self.pos],
+            start,
+        })
+    }
+
+    /// Return the length of the extra separator at the current position, if there is one.
+    fn extra_separator_len(&self) -> Option<usize> {
+        let rest = &self.bytes[self.pos..];
+        self.extra_separators.iter().find_map(|c| {
+            let mut buf = [0; 4];
+            let encoded = c.encode_utf8(&mut buf).as_bytes();
+            rest.starts_with(encoded).then_some(encoded.len())
+        })
+    }
+
+    fn skip_run(&mut self, predicate: impl Fn(&u8) -> bool) {
+        while self.bytes.get(self.pos).is_some_and(&predicate) {
+            self.pos += 1;
+        }
+    }
+}
+
+impl<'a> Iterator for Scanner<'a> {
+    type Item = RawToken<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_token()
+    }
+}
@@ -0,0 +1,183 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::{Version, VersionReq};
+
+/// A list of versions.
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::{Version, VersionReq, Versions};
+///
+/// let versions: Versions = ["2.0", "1.0", "1.5", "1.0"].into_iter().map(Version::from).collect();
+/// let req = VersionReq::parse("<2.0").unwrap();
+///
+/// let mut matching = versions.matching(&req).sorted();
+/// matching.dedup();
+///
+/// assert_eq!(matching.as_slice(), ["1.0", "1.5"].map(Version::from));
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct Versions(Vec<Version>);
+
+impl Versions {
+    /// Sort the versions from oldest to newest.
+    #[must_use]
+    pub fn sorted(mut self) -> Self {
+        self.0.sort();
+        self
+    }
+
+    /// Return the newest version.
+    ///
+    /// If several of the newest versions compare as equal, the first one is returned.
+    #[must_use]
+    pub fn max(&self) -> Option<&Version> {
+        self.0
+            .iter()
+            .reduce(|newest, v| if v > newest { v } else { newest })
+    }
+
+    /// Remove consecutive versions that compare as equal.
+    ///
+    /// Like [`Vec::dedup`], this only removes all duplicates if the versions are sorted.
+    pub fn dedup(&mut self) {
+        self.0.dedup_by(|a, b| a.is_equivalent(b));
+    }
+
+    /// Return the versions that match `req`.
+    #[must_use]
+    pub fn matching(&self, req: &VersionReq) -> Versions {
+        self.0.iter().filter(|v| req.matches(v)).cloned().collect()
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, Version> {
+        self.0.iter()
+    }
+
+    #[must_use]
+    pub fn as_slice(&self) -> &[Version] {
+        &self.0
+    }
+
+    #[must_use]
+    pub fn into_vec(self) -> Vec<Version> {
+        self.0
+    }
+}
+
+impl From<Vec<Version>> for Versions {
+    fn from(versions: Vec<Version>) -> Self {
+        Self(versions)
+    }
+}
+
+impl FromIterator<Version> for Versions {
+    fn from_iter<I: IntoIterator<Item = Version>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for Versions {
+    type Item = Version;
+    type IntoIter = vec::IntoIter<Version>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Versions {
+    type Item = &'a Version;
+    type IntoIter = core::slice::Iter<'a, Version>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Summary of a single version as produced by [`analyze`].
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct VersionInfo<'a> {
+    pub version: &'a Version,
+    /// Zero-based position in sorted order. Versions that compare as equal share a rank.
+    pub rank: usize,
+    pub is_prerelease: bool,
+    /// The first run of letters of the pre-release part, e.g. `rc` for `1.0~rc1`.
+    pub channel: Option<&'a str>,
+}
+
+/// Rank and classify each of `versions`.
+///
+/// The result is in the same order as `versions`. The rank of a version is the number of
+/// versions that are older than it.
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::{analyze, Version};
+///
+/// let versions = ["2.0", "1.0~rc1", "2.0", "1.0"].map(Version::from);
+/// let info = analyze(&versions);
+///
+/// assert_eq!(info.iter().map(|i| i.rank).collect::<Vec<_>>(), [2, 0, 2, 1]);
+/// assert_eq!(info[1].channel, Some("rc"));
+/// ```
+#[must_use]
+pub fn analyze(versions: &[Version]) -> Vec<VersionInfo<'_>> {
+    let mut order: Vec<usize> = (0..versions.len()).collect();
+    order.sort_by(|&a, &b| versions[a].cmp(&versions[b]));
+
+    let mut ranks = vec![0; versions.len()];
+    for (position, pair) in order.windows(2).enumerate() {
+        let [previous, current] = [pair[0], pair[1]];
+        ranks[current] = match versions[previous].cmp(&versions[current]) {
+            Ordering::Equal => ranks[previous],
+            _ => position + 1,
+        };
+    }
+
+    versions
+        .iter()
+        .zip(ranks)
+        .map(|(version, rank)| VersionInfo {
+            version,
+            rank,
+            is_prerelease: version.is_prerelease(),
+            channel: version.prerelease_channel(),
+        })
+        .collect()
+}
+
+/// Return the newest version in `sorted` that is older than or equal to `target`.
+///
+/// `sorted` has to be sorted from oldest to newest. If several versions compare as equal to the
+/// result, the last one is returned.
+///
+/// # Examples
+///
+/// ```
+/// use uapi_version::{ceil, floor, Version};
+///
+/// let installed = ["1.0", "1.5", "2.0"].map(Version::from);
+/// let target = Version::from("1.7");
+///
+/// assert_eq!(floor(&installed, &target), Some(&Version::from("1.5")));
+/// assert_eq!(ceil(&installed, &target), Some(&Version::from("2.0")));
+/// ```
+#[must_use]
+pub fn floor<'a>(sorted: &'a [Version], target: &Version) -> Option<&'a Version> {
+    let end = sorted.partition_point(|v| v <= target);
+    end.checked_sub(1).map(|i| &sorted[i])
+}
+
+/// Return the oldest version in `sorted` that is newer than or equal to `target`.
+///
+/// `sorted` has to be sorted from oldest to newest. If several versions compare as equal to the
+/// result, the first one is returned.
+#[must_use]
+pub fn ceil<'a>(sorted: &'a [Version], target: &Version) -> Option<&'a Version> {
+    sorted.get(sorted.partition_point(|v| v < target))
+}
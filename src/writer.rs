@@ -0,0 +1,51 @@
+use alloc::fmt;
+use alloc::string::String;
+
+use crate::Version;
+
+/// Build a [`Version`] with formatted writes.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt::Write;
+///
+/// use uapi_version::{Version, VersionWriter};
+///
+/// let mut writer = VersionWriter::with_capacity(8);
+/// write!(writer, "{}.{}", 1, 2).unwrap();
+/// write!(writer, ".{}", 3).unwrap();
+///
+/// assert_eq!(writer.finish(), Version::from("1.2.3"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct VersionWriter {
+    buf: String,
+}
+
+impl VersionWriter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a writer that can hold `capacity` bytes without reallocating.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buf: String::with_capacity(capacity),
+        }
+    }
+
+    #[must_use]
+    pub fn finish(self) -> Version {
+        Version::from(self.buf)
+    }
+}
+
+impl fmt::Write for VersionWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.buf.push_str(s);
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+//! Check that comparing versions doesn't allocate.
+//!
+//! This needs its own test binary because it replaces the global allocator.
+#![cfg(feature = "std")]
+
+use std::hint::black_box;
+
+use counting_alloc::{allocations, CountingAllocator};
+use uapi_version::{
+    strverscmp, strverscmp_bytes, strverscmp_observed, strverscmp_with, CompareOptions, Version,
+};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn compare_without_allocations() {
+    assert!(allocations(|| drop(black_box(Vec::<u8>::with_capacity(8)))) > 0);
+
+    let pairs = [
+        ("1.2.3", "1.2.4"),
+        ("1.0~rc1", "1.0"),
+        ("123.45-67.89", "123.45-67.89a"),
+        ("0001", "002"),
+        ("1_2", "12"),
+        ("", "~"),
+        ("α1.β2", "1.2"),
+    ];
+    let versions: Vec<_> = pairs
+        .iter()
+        .map(|(a, b)| (Version::from(*a), Version::from(*b)))
+        .collect();
+    let options = CompareOptions {
+        zero_width_tiebreak: true,
+        extra_separators: &['_'],
+        pad_trailing_zero: true,
+        unify_separators: true,
+        alpha_rank: Some(&|s| i64::from(s == "rc")),
+    };
+
+    let count = allocations(|| {
+        for (a, b) in pairs {
+            black_box(strverscmp(black_box(a), black_box(b)));
+            black_box(strverscmp_bytes(
+                black_box(a.as_bytes()),
+                black_box(b.as_bytes()),
+            ));
+            black_box(strverscmp_with(black_box(a), black_box(b), &options));
+            black_box(strverscmp_observed(
+                black_box(a),
+                black_box(b),
+                &mut |pair| {
+                    black_box(pair);
+                },
+            ));
+        }
+        for (a, b) in &versions {
+            black_box(black_box(a).cmp(black_box(b)));
+        }
+    });
+    assert_eq!(count, 0);
+}
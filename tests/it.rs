@@ -2,7 +2,16 @@
 
 use std::cmp::Ordering;
 
-use uapi_version::{strverscmp, Version};
+use uapi_version::{
+    analyze, ceil, debug_assert_consistent, diverging_component, ensure_newer, floor, is_downgrade,
+    latest_matching, relationship, shortest_unique_prefixes, strverscmp, strverscmp_as_ref,
+    strverscmp_bytes, strverscmp_i8, strverscmp_observed, strverscmp_ordered, strverscmp_strict,
+    strverscmp_with, strverscmp_with_budget, strverscmp_with_precedence, update_kind,
+    CompareOptions, ComparePayload, CompareUnit, ComponentChange, ComponentKind, Direction,
+    DowngradeError, OldestFirst, ParseReqError, ParsedVersion, Relationship, ReqOp, SegmentPair,
+    SeparatorPrecedence, SeparatorStyle, StrictError, SymbolicVersion, UpdateKind, Version,
+    VersionDistance, VersionReq, VersionTokenKind, VersionWriter, Versions, COMMON_ARCHES,
+};
 
 fn assert_ordering(a: &str, b: &str, expected: Ordering) {
     let ordering = strverscmp(a, b);
@@ -28,6 +37,92 @@ fn assert_smaller_list(versions: &[(&str, &str)]) {
     }
 }
 
+fn parse_req(s: &str) -> VersionReq {
+    VersionReq::parse(s).unwrap_or_else(|e| panic!("Failed to parse {s}: {e}"))
+}
+
+/// Versions gathered from the other tests, including invalid characters and leading zeros.
+const CORPUS: &[&str] = &[
+    "",
+    "~1",
+    "~",
+    "-",
+    "^",
+    ".",
+    "_",
+    "ab",
+    "abb",
+    "abc",
+    "Abc",
+    "0",
+    "00",
+    "0_",
+    "0001",
+    "002",
+    "0.0.1",
+    "0.1.0",
+    "0.10.0",
+    "1",
+    "1.0",
+    "1.0.0",
+    "1.00",
+    "1.01",
+    "1.1",
+    "1_1",
+    "1_a",
+    "1a",
+    "1.a",
+    "1~rc1",
+    "1.0~rc1",
+    "1.0~rc_1",
+    "1.0~rc01",
+    "1.0~beta",
+    "1.0~~",
+    "10.0.0",
+    "12",
+    "12_3",
+    "12.3",
+    "122",
+    "122.1",
+    "122.9",
+    "123~rc1",
+    "123~rc1-1",
+    "123",
+    "123.",
+    "123-a",
+    "123-a.1",
+    "123-a1",
+    "123-a1.1",
+    "123-1",
+    "123-1.1",
+    "123-3",
+    "123-3.1",
+    "123^patch1",
+    "123^post1",
+    "123^1",
+    "123.a-1",
+    "123.1-1",
+    "123a-1",
+    "123_aa2-67.89",
+    "123aa+2-67.89",
+    "123.45-67.89",
+    "123.45-67.89a",
+    "123.45-67.ab",
+    "124",
+    "124-1",
+    "245",
+    "270",
+    "2024.01.15",
+    "20240115",
+    "git-20240115",
+    "5.2",
+    "5.20",
+    "5.2.1",
+    "٠",
+    "1٢",
+    "a b",
+];
+
 #[test]
 fn constructing() {
     let _ = Version::from(String::from("a"));
@@ -46,8 +141,8 @@ fn order() {
         "0.0.1",
         "0.1.0",
         "0.10.0",
-        "1.0.0",
         "0001",
+        "1.0.0",
         "002",
         "10.0.0",
         "12",
@@ -241,3 +336,2445 @@ fn uapi_spec_examples() {
         ("1+2+3", "1.3.3", Ordering::Greater),
     ]);
 }
+
+#[test]
+fn ordered() {
+    assert_eq!(
+        strverscmp_ordered("1.0", "2.0", Direction::Ascending),
+        Ordering::Less
+    );
+    assert_eq!(
+        strverscmp_ordered("1.0", "2.0", Direction::Descending),
+        Ordering::Greater
+    );
+    assert_eq!(
+        strverscmp_ordered("0_", "0", Direction::Descending),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn equivalent() {
+    let pairs = [
+        ("0_", "0"),
+        ("_0_", "0"),
+        ("_0", "0"),
+        ("0", "0___"),
+        ("", "_"),
+        ("_", ""),
+        ("_", "_"),
+        ("~", "~"),
+    ];
+    for (a, b) in pairs {
+        assert!(Version::from(a).is_equivalent(&Version::from(b)));
+    }
+    assert!(!Version::from("").is_equivalent(&Version::from("~")));
+    assert!(!Version::from("1.0").is_equivalent(&Version::from("1.1")));
+}
+
+#[test]
+fn in_range() {
+    let lo = Version::from("1.0");
+    let hi = Version::from("2.0");
+
+    for (inclusive, at_lo, at_hi) in [
+        ((true, true), true, true),
+        ((true, false), true, false),
+        ((false, true), false, true),
+        ((false, false), false, false),
+    ] {
+        assert_eq!(Version::from("1.0").in_range(&lo, &hi, inclusive), at_lo);
+        assert_eq!(Version::from("2.0").in_range(&lo, &hi, inclusive), at_hi);
+        assert!(Version::from("1.5").in_range(&lo, &hi, inclusive));
+        assert!(!Version::from("1.0~rc1").in_range(&lo, &hi, inclusive));
+        assert!(!Version::from("2.0.1").in_range(&lo, &hi, inclusive));
+    }
+
+    let point = Version::from("1.0");
+    assert!(Version::from("1.0").in_range(&point, &point, (true, true)));
+    assert!(Version::from("1.0_").in_range(&point, &point, (true, true)));
+    assert!(!Version::from("1.0").in_range(&point, &point, (true, false)));
+    assert!(!Version::from("1.0").in_range(&point, &point, (false, true)));
+    assert!(!Version::from("1.0").in_range(&point, &point, (false, false)));
+}
+
+#[test]
+fn numeric_skeleton() {
+    let skeleton = |v: &str| Version::from(v).numeric_skeleton();
+
+    assert_eq!(skeleton("1.2rc1"), skeleton("1.2beta2"));
+    assert_ne!(skeleton("1.2rc1"), skeleton("1.3"));
+    assert_eq!(skeleton("1.2rc1"), "1.2");
+    assert_eq!(skeleton("1.3"), "1.3");
+    assert_eq!(skeleton("123.45-67.89"), "123.45");
+    assert_eq!(skeleton("1.0~rc1"), "1.0");
+    assert_eq!(skeleton("123^patch1"), "123");
+    assert_eq!(skeleton("abc"), "");
+}
+
+#[test]
+fn default_precedence() {
+    let versions = [
+        "",
+        "~",
+        "~1",
+        "0_",
+        "1",
+        "1.0",
+        "1.01",
+        "1~rc1",
+        "1-1",
+        "1^1",
+        "1.a",
+        "1a",
+        "12_3",
+        "12.3",
+        "123",
+        "123..0",
+        "123.0",
+        "a",
+        "A",
+        "1٠١",
+        "systemd-123",
+    ];
+    for a in versions {
+        for b in versions {
+            assert_eq!(
+                strverscmp_with_precedence(a, b, &SeparatorPrecedence::default()),
+                strverscmp(a, b),
+                "{a} {b}"
+            );
+        }
+    }
+}
+
+#[test]
+fn custom_precedence() {
+    let precedence = SeparatorPrecedence {
+        caret: 0,
+        tilde: 1,
+        end: 2,
+        ..SeparatorPrecedence::UAPI
+    };
+    let cmp = |a, b| strverscmp_with_precedence(a, b, &precedence);
+
+    assert_eq!(cmp("1^1", "1~1"), Ordering::Less);
+    assert_eq!(cmp("1^1", "1"), Ordering::Less);
+    assert_eq!(cmp("1~1", "1"), Ordering::Less);
+    assert_eq!(cmp("1-1", "1"), Ordering::Greater);
+
+    let numeric_first = SeparatorPrecedence {
+        numeric: 5,
+        alpha: 6,
+        ..SeparatorPrecedence::UAPI
+    };
+    assert_eq!(
+        strverscmp_with_precedence("1.1", "1.a", &numeric_first),
+        Ordering::Less
+    );
+
+    let unified = SeparatorPrecedence {
+        caret: 2,
+        dot: 2,
+        ..SeparatorPrecedence::UAPI
+    };
+    assert_eq!(
+        strverscmp_with_precedence("1^1", "1.1", &unified),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn strip_prerelease() {
+    for (original, stripped) in [
+        ("1.0.0~rc1", "1.0.0"),
+        ("123~rc1-99.99", "123-99.99"),
+        ("1.0~rc1^2-5", "1.0^2-5"),
+        ("1.0~rc1.git20240101", "1.0"),
+    ] {
+        let original = Version::from(original);
+        let stripped_version = original.strip_prerelease();
+        assert_eq!(stripped_version, Version::from(stripped));
+        assert!(stripped_version > original);
+    }
+
+    for unchanged in ["1.0.0", "1.0-5~rc1", "1.0^2~rc1", ""] {
+        assert_eq!(
+            Version::from(unchanged).strip_prerelease(),
+            Version::from(unchanged)
+        );
+    }
+}
+
+#[test]
+fn components() {
+    let version = Version::from("123.45-67.89");
+    assert_eq!(version.component_count(), 3);
+    assert_eq!(version.component(0), Some("123"));
+    assert_eq!(version.component(1), Some("45-67"));
+    assert_eq!(version.component(2), Some("89"));
+    assert_eq!(version.component(3), None);
+    assert_eq!(version.last_component(), Some("89"));
+    assert!(version.rsplit_components().eq(["89", "45-67", "123"]));
+
+    let version = Version::from("1.2.");
+    assert_eq!(version.component_count(), 3);
+    assert_eq!(version.component(2), Some(""));
+    assert_eq!(version.last_component(), Some(""));
+    assert!(version
+        .rsplit_components()
+        .eq(version.split_components().rev()));
+
+    let version = Version::from("");
+    assert_eq!(version.component_count(), 1);
+    assert_eq!(version.component(0), Some(""));
+    assert_eq!(version.last_component(), Some(""));
+}
+
+#[test]
+fn from_trimmed() {
+    assert_eq!(Version::from_trimmed("  1.0  ").as_str(), "1.0");
+    assert_eq!(Version::from_trimmed("\t1. 0\r\n").as_str(), "1. 0");
+    assert_eq!(Version::from_trimmed("   ").as_str(), "");
+    assert_ordering("1. 0", "1.0", Ordering::Equal);
+    assert_ordering("  1.0  ", "1.0", Ordering::Equal);
+}
+
+#[test]
+fn pick() {
+    let old = Version::from("1.0");
+    let new = Version::from("2.0");
+    assert_eq!(old.clone().pick_newer(new.clone()), new);
+    assert_eq!(new.clone().pick_newer(old.clone()), new);
+    assert_eq!(old.clone().pick_older(new.clone()), old);
+    assert_eq!(new.clone().pick_older(old.clone()), old);
+
+    let a = Version::from("1_");
+    let b = Version::from("1");
+    assert_eq!(a.clone().pick_newer(b.clone()), a);
+    assert_eq!(b.clone().pick_newer(a.clone()), b);
+    assert_eq!(a.clone().pick_older(b.clone()), a);
+    assert_eq!(b.clone().pick_older(a), b);
+}
+
+#[test]
+fn req_comparison_operators() {
+    let matches = |req: &str, version: &str| parse_req(req).matches(&Version::from(version));
+
+    assert!(matches("1.0", "1.0"));
+    assert!(matches("=1.0", "1.0_"));
+    assert!(!matches("=1.0", "1.0.0"));
+    assert!(matches(">1.0", "1.0.1"));
+    assert!(!matches(">1.0", "1.0"));
+    assert!(matches(">=1.0", "1.0"));
+    assert!(!matches(">=1.0", "1.0~rc1"));
+    assert!(matches("<1.0", "1.0~rc1"));
+    assert!(matches("<=1.0", "1.0"));
+    assert!(matches(" >= 1.2 , < 2.0 ", "1.5"));
+    assert!(!matches(">=1.2, <2.0", "2.0"));
+}
+
+#[test]
+fn req_caret_tilde() {
+    let matches = |req: &str, version: &str| parse_req(req).matches(&Version::from(version));
+
+    assert!(matches("^1.2", "1.2"));
+    assert!(matches("^1.2", "1.9"));
+    assert!(matches("^1.2", "1.10.3-4"));
+    assert!(!matches("^1.2", "1.1"));
+    assert!(!matches("^1.2", "2.0"));
+    assert!(!matches("^1.2", "2.0~rc1"));
+    assert!(matches("^01.2", "1.3"));
+
+    assert!(matches("~1.2", "1.2.9"));
+    assert!(!matches("~1.2", "1.3"));
+    assert!(!matches("~1.2", "1.1"));
+    assert!(matches("~1", "1.9"));
+    assert!(!matches("~1", "2.0"));
+
+    // The patch separator inside a version isn't an operator.
+    assert!(matches("1.0^1", "1.0^1"));
+    assert!(matches("=~rc1", "~rc1"));
+}
+
+#[test]
+fn req_parse_errors() {
+    assert_eq!(VersionReq::parse(""), Err(ParseReqError::EmptyComparator));
+    assert_eq!(
+        VersionReq::parse(">=1.0,"),
+        Err(ParseReqError::EmptyComparator)
+    );
+    assert_eq!(VersionReq::parse(">="), Err(ParseReqError::MissingVersion));
+    assert_eq!(
+        VersionReq::parse("^abc"),
+        Err(ParseReqError::MissingNumericComponent)
+    );
+    assert_eq!(
+        ">=".parse::<VersionReq>(),
+        Err(ParseReqError::MissingVersion)
+    );
+}
+
+#[test]
+fn req_display() {
+    let req = parse_req(">= 1.2,<2.0, ^1");
+    assert_eq!(req.to_string(), ">=1.2, <2.0, ^1");
+    assert_eq!(req.comparators().len(), 3);
+    assert_eq!(req.comparators()[1].op, ReqOp::Less);
+}
+
+#[test]
+fn version_as_req() {
+    let version = Version::from("1.2.3");
+
+    let req = version.as_req(ReqOp::GreaterEq);
+    assert!(req.matches(&Version::from("1.2.4")));
+    assert!(req.matches(&version));
+    assert!(!req.matches(&Version::from("1.2.3~rc1")));
+    assert_eq!(req, parse_req(">=1.2.3"));
+
+    let req = version.as_req(ReqOp::Caret);
+    assert!(req.matches(&Version::from("1.9")));
+    assert!(!req.matches(&Version::from("2.0")));
+
+    assert_eq!(version.as_req(ReqOp::Exact).to_string(), "=1.2.3");
+    assert!(version.as_req(ReqOp::Less).matches(&Version::from("1.2")));
+}
+
+#[test]
+fn i8_results() {
+    assert_eq!(strverscmp_i8("1.0", "2.0"), -1);
+    assert_eq!(strverscmp_i8("1.0", "1.0_"), 0);
+    assert_eq!(strverscmp_i8("2.0", "1.0"), 1);
+
+    let pairs = [("1", "2"), ("2", "2"), ("3", "2")];
+    let packed = pairs.map(|(a, b)| strverscmp_i8(a, b));
+    assert_eq!(packed, [-1, 0, 1]);
+}
+
+#[test]
+fn tokens() {
+    let version = Version::from("01.a_b~^-");
+    let tokens: Vec<_> = version.tokens().map(|t| (t.kind(), t.as_str())).collect();
+    assert_eq!(
+        tokens,
+        [
+            (VersionTokenKind::Numeric, "01"),
+            (VersionTokenKind::Dot, "."),
+            (VersionTokenKind::Alpha, "a"),
+            (VersionTokenKind::Alpha, "b"),
+            (VersionTokenKind::Tilde, "~"),
+            (VersionTokenKind::Caret, "^"),
+            (VersionTokenKind::Dash, "-"),
+        ]
+    );
+    assert_eq!(Version::from("_α_").tokens().count(), 0);
+    assert!((&version).into_iter().eq(version.tokens()));
+}
+
+#[test]
+fn iter_numeric() {
+    assert!(Version::from("1.20.3-4").iter_numeric().eq([1, 20, 3, 4]));
+    assert!(Version::from("007~rc1").iter_numeric().eq([7, 1]));
+    assert!(Version::from("1.99999999999999999999.2")
+        .iter_numeric()
+        .eq([1, 2]));
+    assert_eq!(Version::from("abc").iter_numeric().count(), 0);
+}
+
+/// Exercise runs that span several words and end at every offset within a word.
+#[test]
+fn long_runs() {
+    for len in 1..40 {
+        let digits = "9".repeat(len);
+        let letters = "aZ".repeat(len);
+
+        assert_ordering(&format!("{digits}8"), &format!("{digits}9"), Ordering::Less);
+        assert_ordering(&digits, &format!("{digits}0"), Ordering::Less);
+        assert_ordering(
+            &format!("0{digits}.1"),
+            &format!("{digits}.1"),
+            Ordering::Equal,
+        );
+        assert_ordering(
+            &format!("{digits}a"),
+            &format!("{digits}.a"),
+            Ordering::Greater,
+        );
+        assert_ordering(&format!("{digits}٠"), &digits, Ordering::Equal);
+
+        assert_ordering(
+            &format!("{letters}a"),
+            &format!("{letters}b"),
+            Ordering::Less,
+        );
+        assert_ordering(&letters, &format!("{letters}Z"), Ordering::Less);
+        assert_ordering(
+            &format!("{letters}1"),
+            &format!("{letters}~1"),
+            Ordering::Greater,
+        );
+        assert_ordering(
+            &format!("{letters}@"),
+            &format!("{letters}`"),
+            Ordering::Equal,
+        );
+        assert_ordering(&format!("{letters}[{{"), &letters, Ordering::Equal);
+    }
+}
+
+#[test]
+fn latest_matching_versions() {
+    let versions = ["1.0", "2.0~rc1", "1.5_", "1.5", "0.9", "2.0"].map(Version::from);
+
+    let latest = |req: &str| latest_matching(&versions, &parse_req(req)).map(Version::as_str);
+
+    assert_eq!(latest("<2.0"), Some("2.0~rc1"));
+    assert_eq!(latest("^1"), Some("1.5_"));
+    assert_eq!(latest(">=1.0"), Some("2.0"));
+    assert_eq!(latest(">2.0"), None);
+    assert_eq!(latest_matching(&[], &parse_req(">=1.0")), None);
+}
+
+#[test]
+fn pre_release_tags() {
+    assert_smaller_list(&[
+        // Alphabetical tags compare lexicographically.
+        ("1~alpha", "1~beta"),
+        ("1~beta", "1~rc"),
+        ("1~RC", "1~rc"),
+        // Numerical tags are newer than alphabetical ones.
+        ("1~rc", "1~2"),
+        ("1~beta10", "1~2"),
+        // Numbers within a tag compare numerically.
+        ("1~2", "1~10"),
+        ("1~beta9", "1~beta10"),
+        ("1~rc9", "1~rc10"),
+        ("1~rc1", "1~rc1.1"),
+        ("1~beta", "1~beta1"),
+        // Any pre-release is older than the release.
+        ("1~rc10", "1"),
+        ("1~99", "1"),
+    ]);
+}
+
+#[test]
+fn core_version() {
+    let core = |v: &str| Version::from(v).core_version().into_string();
+
+    assert_eq!(core("1.0~rc1-5^2"), "1.0~rc1");
+    assert_eq!(core("1.0~rc1^2-5"), "1.0~rc1");
+    assert_eq!(core("1.0^2~rc1-5"), "1.0");
+    assert_eq!(core("1.0-5~rc1^2"), "1.0");
+    assert_eq!(core("1.0-5^2~rc1"), "1.0");
+    assert_eq!(core("1.0^2-5~rc1"), "1.0");
+    assert_eq!(core("1.0~rc1"), "1.0~rc1");
+    assert_eq!(core("1.0"), "1.0");
+    assert_eq!(core("-5"), "");
+}
+
+#[test]
+fn adversarial_input() {
+    let long_digits = "9".repeat(100_000);
+    let long_zeros = "0".repeat(100_000);
+    let long_mixed = "1a~-^._".repeat(20_000);
+    let long_separators = "~-^.".repeat(25_000);
+    let inputs = [
+        "",
+        "~",
+        "~~~~",
+        "....",
+        "----",
+        "^^^^",
+        "^-~.",
+        ".~^-",
+        "0",
+        "00",
+        "٠",
+        "\u{0}",
+        &long_digits,
+        &long_zeros,
+        &long_mixed,
+        &long_separators,
+    ];
+    for a in inputs {
+        for b in inputs {
+            let ordering = strverscmp(a, b);
+            assert_eq!(strverscmp(b, a), ordering.reverse());
+            assert_eq!(strverscmp_bytes(a.as_bytes(), b.as_bytes()), ordering);
+        }
+    }
+
+    let invalid_utf8: [&[u8]; 5] = [b"\xff", b"1.\xff0", b"\xc3", b"1\xe2\x82", b"\x80~\x80"];
+    for a in invalid_utf8 {
+        for b in invalid_utf8 {
+            let ordering = strverscmp_bytes(a, b);
+            assert_eq!(strverscmp_bytes(b, a), ordering.reverse());
+        }
+    }
+    assert_eq!(strverscmp_bytes(b"1.\xff0", b"1.0"), Ordering::Equal);
+}
+
+#[test]
+fn from_filename() {
+    let extract = |name: &str| Version::from_filename(name).map(Version::into_string);
+
+    assert_eq!(
+        extract("linux-6.1.0-rc2.conf").as_deref(),
+        Some("6.1.0-rc2")
+    );
+    assert_eq!(extract("linux-6.1.0.efi").as_deref(), Some("6.1.0"));
+    assert_eq!(extract("systemd-boot_255.4").as_deref(), Some("255.4"));
+    assert_eq!(
+        extract("initrd-6.8.9 (fallback).img").as_deref(),
+        Some("6.8.9")
+    );
+    assert_eq!(extract("entry.conf"), None);
+    assert_eq!(extract(""), None);
+}
+
+#[test]
+fn leading_zeros() {
+    assert_ordering_list(&[
+        ("1.01", "1.1", Ordering::Equal),
+        ("0001", "1", Ordering::Equal),
+        ("007", "7", Ordering::Equal),
+        ("00", "0", Ordering::Equal),
+        ("1.00", "1.0", Ordering::Equal),
+        ("0001", "002", Ordering::Less),
+        ("010", "9", Ordering::Greater),
+        ("0", "a", Ordering::Greater),
+    ]);
+}
+
+#[test]
+fn zero_width_tiebreak() {
+    let options = CompareOptions {
+        zero_width_tiebreak: true,
+        ..CompareOptions::default()
+    };
+    let cmp = |a, b| strverscmp_with(a, b, &options);
+
+    assert_eq!(cmp("1.01", "1.1"), Ordering::Less);
+    assert_eq!(cmp("1.1", "1.01"), Ordering::Greater);
+    assert_eq!(cmp("1.001", "1.01"), Ordering::Less);
+    assert_eq!(cmp("1.01", "1.01"), Ordering::Equal);
+    assert_eq!(cmp("1.01", "1.2"), Ordering::Less);
+    assert_eq!(cmp("0", "00"), Ordering::Greater);
+
+    assert_eq!(
+        strverscmp_with("1.01", "1.1", &CompareOptions::default()),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn versions_collection() {
+    let versions: Versions = ["2.0", "1.0_", "1.5", "1.0", "2.0~rc1", "1.5"]
+        .into_iter()
+        .map(Version::from)
+        .collect();
+
+    assert_eq!(versions.max(), Some(&Version::from("2.0")));
+    assert_eq!(Versions::default().max(), None);
+
+    let mut sorted = versions.clone().sorted();
+    sorted.dedup();
+    assert_eq!(
+        sorted.as_slice(),
+        ["1.0_", "1.5", "2.0~rc1", "2.0"].map(Version::from)
+    );
+
+    let matching = versions.matching(&parse_req("^1")).sorted();
+    assert_eq!(
+        matching.into_vec(),
+        ["1.0_", "1.0", "1.5", "1.5"].map(Version::from)
+    );
+
+    let strings: Vec<String> = versions.into_iter().map(Version::into_string).collect();
+    assert_eq!(strings.len(), 6);
+}
+
+#[test]
+fn extra_separators() {
+    let options = CompareOptions {
+        extra_separators: &['_'],
+        ..CompareOptions::default()
+    };
+    let cmp = |a, b| strverscmp_with(a, b, &options);
+
+    assert_eq!(cmp("12_3", "12.3"), Ordering::Equal);
+    assert_eq!(cmp("12_3", "123"), Ordering::Less);
+    assert_eq!(cmp("1_2_3", "1.3.3"), Ordering::Less);
+    assert_eq!(cmp("1_", "1"), Ordering::Greater);
+    assert_eq!(cmp("1+", "1"), Ordering::Equal);
+
+    let options = CompareOptions {
+        extra_separators: &['·', '+'],
+        ..CompareOptions::default()
+    };
+    assert_eq!(strverscmp_with("1·2", "1.2", &options), Ordering::Equal);
+    assert_eq!(strverscmp_with("1+2", "1.2", &options), Ordering::Equal);
+
+    let default = CompareOptions::default();
+    assert_eq!(strverscmp_with("12_3", "123", &default), Ordering::Less);
+    assert_eq!(strverscmp_with("12_3", "12.3", &default), Ordering::Greater);
+}
+
+#[test]
+fn alpha_rank() {
+    let rank = |s: &str| match s {
+        "dev" => -1,
+        "alpha" => 0,
+        "beta" => 1,
+        "rc" | "RC" => 2,
+        _ => 3,
+    };
+    let options = CompareOptions {
+        alpha_rank: Some(&rank),
+        ..CompareOptions::default()
+    };
+    let cmp = |a, b| strverscmp_with(a, b, &options);
+
+    assert_eq!(cmp("1.0~RC1", "1.0~beta2"), Ordering::Greater);
+    assert_eq!(cmp("1.0~dev", "1.0~alpha"), Ordering::Less);
+    assert_eq!(cmp("1.0~rc1", "1.0~beta2"), Ordering::Greater);
+    assert_eq!(cmp("1.0~rc1", "1.0~rc2"), Ordering::Less);
+    assert_eq!(cmp("1.0~zzz", "1.0"), Ordering::Less);
+    // Equal ranks fall back to the byte order.
+    assert_eq!(cmp("1.0~nightly", "1.0~zzz"), Ordering::Less);
+    assert_eq!(cmp("1.0~RC", "1.0~rc"), Ordering::Less);
+
+    let default = CompareOptions::default();
+    assert_eq!(
+        strverscmp_with("1.0~RC1", "1.0~beta2", &default),
+        Ordering::Less
+    );
+    assert_eq!(
+        strverscmp_with("1.0~dev", "1.0~alpha", &default),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn unify_separators() {
+    let options = CompareOptions {
+        unify_separators: true,
+        ..CompareOptions::default()
+    };
+    let cmp = |a, b| strverscmp_with(a, b, &options);
+
+    assert_eq!(cmp("1.0.5", "1.0-5"), Ordering::Equal);
+    assert_eq!(cmp("1.0^5", "1.0-5"), Ordering::Equal);
+    assert_eq!(cmp("1-2^3", "1.2.3"), Ordering::Equal);
+    assert_eq!(cmp("123-99.99", "123.45-67.89"), Ordering::Greater);
+    assert_eq!(cmp("1.0-5", "1.0.6"), Ordering::Less);
+    assert_eq!(cmp("1.0~rc1", "1.0"), Ordering::Less);
+    assert_eq!(cmp("1.0~rc1", "1.0.rc1"), Ordering::Less);
+
+    let default = CompareOptions::default();
+    assert_eq!(
+        strverscmp_with("1.0.5", "1.0-5", &default),
+        Ordering::Greater
+    );
+    assert_eq!(
+        strverscmp_with("1.0^5", "1.0-5", &default),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn version_tuple() {
+    let tuple = |v: &str| Version::from(v).version_tuple();
+
+    assert_eq!(tuple("1.2"), (1, 2, 0));
+    assert_eq!(tuple("1.2.3.4"), (1, 2, 3));
+    assert_eq!(tuple("01.002.3"), (1, 2, 3));
+    assert_eq!(tuple("1.2rc1-3"), (1, 2, 0));
+    assert_eq!(tuple("1.2~rc1"), (1, 2, 0));
+    assert_eq!(tuple("6.1.0-rc2"), (6, 1, 0));
+    assert_eq!(tuple("1.99999999999999999999"), (1, u64::MAX, 0));
+    assert_eq!(tuple("abc"), (0, 0, 0));
+    assert_eq!(tuple(""), (0, 0, 0));
+}
+
+#[test]
+fn compare_with_u64() {
+    assert_eq!(
+        Version::from("225").partial_cmp(&224u64),
+        Some(Ordering::Greater)
+    );
+    assert_eq!(
+        Version::from("225").partial_cmp(&225u64),
+        Some(Ordering::Equal)
+    );
+    assert_eq!(
+        Version::from("007").partial_cmp(&8u64),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        Version::from("99999999999999999999").partial_cmp(&u64::MAX),
+        Some(Ordering::Greater)
+    );
+    assert!(Version::from("225") > 224u64);
+    assert!(Version::from("225") == 225u64);
+    assert!(224u64 < Version::from("225"));
+    assert!(225u64 == Version::from("225"));
+
+    for non_numeric in ["1.0~rc", "1.0", "1_", "", "abc", "1٢"] {
+        let version = Version::from(non_numeric);
+        assert_eq!(version.partial_cmp(&1u64), None);
+        assert!(version != 1u64);
+        assert!(version.partial_cmp(&0u64).is_none());
+        assert!(1u64 != version);
+    }
+}
+
+#[test]
+fn analyze_versions() {
+    let versions = [
+        "1.0",
+        "2.0~rc1",
+        "1.0",
+        "2.0",
+        "01.0",
+        "0.9~beta.2",
+        "1.0-1~rc1",
+    ]
+    .map(Version::from);
+    let info = analyze(&versions);
+
+    let ranks: Vec<_> = info.iter().map(|i| i.rank).collect();
+    assert_eq!(ranks, [1, 5, 1, 6, 1, 0, 4]);
+    assert!(info.iter().zip(&versions).all(|(i, v)| i.version == v));
+
+    let prerelease: Vec<_> = info.iter().map(|i| i.is_prerelease).collect();
+    assert_eq!(prerelease, [false, true, false, false, false, true, false]);
+
+    let channels: Vec<_> = info.iter().map(|i| i.channel).collect();
+    assert_eq!(
+        channels,
+        [None, Some("rc"), None, None, None, Some("beta"), None]
+    );
+
+    assert!(analyze(&[]).is_empty());
+    assert_eq!(analyze(&[Version::from("1~2")])[0].channel, None);
+}
+
+#[test]
+fn with_component() {
+    let version = Version::from("1.2.3");
+    assert_eq!(version.with_component(0, "2"), Some(Version::from("2.2.3")));
+    assert_eq!(version.with_component(1, "5"), Some(Version::from("1.5.3")));
+    assert_eq!(
+        version.with_component(2, "4~rc1"),
+        Some(Version::from("1.2.4~rc1"))
+    );
+    assert_eq!(version.with_component(1, ""), Some(Version::from("1..3")));
+
+    assert_eq!(version.with_component(3, "4"), None);
+    assert_eq!(version.with_component(usize::MAX, "4"), None);
+    assert_eq!(version.with_component(1, "5.0"), None);
+    assert_eq!(version.with_component(1, "5 "), None);
+    assert_eq!(version.with_component(1, "ü"), None);
+
+    assert_eq!(
+        Version::from("").with_component(0, "1"),
+        Some(Version::from("1"))
+    );
+    assert_eq!(
+        Version::from("1.2-3.4").with_component(1, "9"),
+        Some(Version::from("1.9.4"))
+    );
+}
+
+#[test]
+fn point_release() {
+    let is_point_release = |version: &str, base: &str| {
+        Version::from(version).is_point_release_of(&Version::from(base))
+    };
+
+    assert!(is_point_release("1.2.3", "1.2"));
+    assert!(is_point_release("1.2.0", "1.2"));
+    assert!(is_point_release("1.2.3.4", "1.2"));
+    assert!(is_point_release("01.2.3", "1.02"));
+    assert!(is_point_release("5.2.1", "5.2"));
+
+    assert!(!is_point_release("5.20", "5.2"));
+    assert!(!is_point_release("5.20.1", "5.2"));
+    assert!(!is_point_release("1.3", "1.2"));
+    assert!(!is_point_release("1.2", "1.2"));
+    assert!(!is_point_release("1.2", "1.2.3"));
+    assert!(!is_point_release("1.3.1", "1.2"));
+    assert!(!is_point_release("1.2~rc1.1", "1.2"));
+}
+
+#[test]
+fn eq_ord_mismatch() {
+    // `==` compares the underlying strings, `Ord` follows the specification.
+    let a = Version::from("0_");
+    let b = Version::from("0");
+    assert_ne!(a, b);
+    assert_eq!(a.cmp(&b), Ordering::Equal);
+
+    debug_assert_consistent(&a, &a);
+    debug_assert_consistent(&a, &Version::from("1"));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "`==` and `Ord` disagree")]
+fn debug_assert_consistent_panics() {
+    debug_assert_consistent(&Version::from("0_"), &Version::from("0"));
+}
+
+#[test]
+fn normalized() {
+    assert_eq!(
+        Version::from("01.002~rc_1").normalized().as_str(),
+        "1.2~rc1"
+    );
+    assert_eq!(Version::from("1_1").normalized().as_str(), "1_1");
+    assert_eq!(Version::from("a__+b").normalized().as_str(), "a_b");
+    assert_eq!(Version::from("000").normalized().as_str(), "0");
+    assert_eq!(Version::from("1 a~").normalized().as_str(), "1a~");
+    assert_eq!(Version::from("").normalized().as_str(), "");
+
+    for a in CORPUS.iter().copied().map(Version::from) {
+        let normalized_a = a.normalized();
+        assert_eq!(
+            normalized_a.clone().into_version().normalized(),
+            normalized_a
+        );
+        for b in CORPUS.iter().copied().map(Version::from) {
+            let normalized_b = b.normalized();
+            assert_eq!(normalized_a.cmp(&normalized_b), a.cmp(&b), "{a} vs {b}");
+            assert_eq!(
+                normalized_a == normalized_b,
+                a.is_equivalent(&b),
+                "{a} vs {b}"
+            );
+        }
+    }
+}
+
+#[test]
+fn normalize_into() {
+    let mut buf = String::from("leftover");
+    for (version, expected) in [
+        ("01.002~rc_1", "1.2~rc1"),
+        ("1_1", "1_1"),
+        ("", ""),
+        ("000", "0"),
+    ] {
+        Version::from(version).normalize_into(&mut buf);
+        assert_eq!(buf, expected);
+    }
+
+    for version in CORPUS.iter().copied().map(Version::from) {
+        version.normalize_into(&mut buf);
+        assert_eq!(buf, version.normalized().as_str());
+    }
+}
+
+#[test]
+fn snapshot_date() {
+    let date = |s: &str| Version::from(s).snapshot_date();
+
+    assert_eq!(date("git-20240115"), Some((2024, 1, 15)));
+    assert_eq!(date("20240115"), Some((2024, 1, 15)));
+    assert_eq!(date("1.0^20231231git"), Some((2023, 12, 31)));
+    assert_eq!(date("20240229"), Some((2024, 2, 29)));
+    assert_eq!(date("20000229"), Some((2000, 2, 29)));
+    assert_eq!(date("12345678.20240115"), Some((2024, 1, 15)));
+
+    assert_eq!(date("1.2.3"), None);
+    assert_eq!(date(""), None);
+    assert_eq!(date("2024.01.15"), None);
+    assert_eq!(date("202401150"), None);
+    assert_eq!(date("20241315"), None);
+    assert_eq!(date("20240100"), None);
+    assert_eq!(date("20230229"), None);
+    assert_eq!(date("19000229"), None);
+    assert_eq!(date("20240431"), None);
+
+    assert!(Version::from("git-20240115") < Version::from("git-20240116"));
+}
+
+#[test]
+fn component_diff() {
+    let diff = |a: &str, b: &str| Version::from(a).component_diff(&Version::from(b));
+    let change = |index, old: Option<&str>, new: Option<&str>| ComponentChange {
+        index,
+        old: old.map(String::from),
+        new: new.map(String::from),
+    };
+
+    assert_eq!(diff("1.2.3", "1.2.9"), [change(2, Some("3"), Some("9"))]);
+    assert_eq!(
+        diff("1.2.3", "2.2.4"),
+        [
+            change(0, Some("1"), Some("2")),
+            change(2, Some("3"), Some("4"))
+        ]
+    );
+    assert_eq!(diff("1.2", "1.2.1"), [change(2, None, Some("1"))]);
+    assert_eq!(
+        diff("1.2.3.4", "1.2"),
+        [change(2, Some("3"), None), change(3, Some("4"), None)]
+    );
+    assert_eq!(diff("1.02", "1.2"), []);
+    assert_eq!(diff("1.2", "1.2"), []);
+    assert_eq!(diff("", ""), []);
+}
+
+#[test]
+fn approx_eq() {
+    let approx_eq =
+        |a: &str, b: &str, tolerance| Version::from(a).approx_eq(&Version::from(b), tolerance);
+
+    assert!(approx_eq("1.2.3", "1.2.5", 2));
+    assert!(approx_eq("1.2.5", "1.2.3", 2));
+    assert!(approx_eq("1.2.3", "1.2.3", 0));
+    assert!(approx_eq("01.2.3", "1.02.4", 1));
+    assert!(approx_eq("7", "9", 2));
+    assert!(approx_eq("1.0", "1.18446744073709551615", u64::MAX));
+
+    assert!(!approx_eq("1.2.3", "1.2.6", 2));
+    assert!(!approx_eq("1.2.3", "1.2.4", 0));
+    assert!(!approx_eq("1.2.3", "1.3.3", 2));
+    assert!(!approx_eq("1.2.3", "1.2.3.1", 2));
+    assert!(!approx_eq("2.3", "1.2.3", 2));
+
+    // Non-numerical last components must be equal.
+    assert!(approx_eq("1.2.rc1", "1.2.rc1", 2));
+    assert!(approx_eq("1.2.3_", "1.2.3", 0));
+    assert!(!approx_eq("1.2.rc1", "1.2.rc2", 2));
+    assert!(!approx_eq("1.2.3a", "1.2.4", 2));
+}
+
+#[test]
+fn diverging_components() {
+    assert_eq!(diverging_component("1.2.3", "2.0.0"), Some(0));
+    assert_eq!(diverging_component("1.2.3", "1.3.0"), Some(1));
+    assert_eq!(diverging_component("1.2.3", "1.2.4"), Some(2));
+    assert_eq!(diverging_component("1.2.3", "1.2.3.1"), Some(3));
+    assert_eq!(diverging_component("1.2.3.1", "1.2.3"), Some(3));
+    assert_eq!(diverging_component("1.2", "1.2."), Some(2));
+    assert_eq!(diverging_component("5.2", "5.20"), Some(1));
+    assert_eq!(diverging_component("1.2~rc1", "1.2"), Some(1));
+
+    assert_eq!(diverging_component("1.2.3", "1.2.3"), None);
+    assert_eq!(diverging_component("01.2_", "1.02"), None);
+    assert_eq!(diverging_component("", ""), None);
+}
+
+#[test]
+fn parts() {
+    let parts = |s: &str| {
+        let version = Version::from(s);
+        (
+            version.upstream().to_owned(),
+            version.prerelease_tag().map(String::from),
+            version.patch().map(String::from),
+            version.release().map(String::from),
+        )
+    };
+    let owned =
+        |upstream: &str, prerelease: Option<&str>, patch: Option<&str>, release: Option<&str>| {
+            (
+                upstream.to_owned(),
+                prerelease.map(String::from),
+                patch.map(String::from),
+                release.map(String::from),
+            )
+        };
+
+    assert_eq!(parts("1.0"), owned("1.0", None, None, None));
+    assert_eq!(parts("1.0-5"), owned("1.0", None, None, Some("5")));
+    assert_eq!(
+        parts("1.0~rc1^2-5"),
+        owned("1.0", Some("rc1"), Some("2"), Some("5"))
+    );
+    assert_eq!(parts("1.0^2~rc1"), owned("1.0", None, Some("2~rc1"), None));
+    assert_eq!(parts("1.0-1~rc1"), owned("1.0", None, None, Some("1~rc1")));
+    assert_eq!(parts("1.0~-a-b"), owned("1.0", Some(""), None, Some("a-b")));
+    assert_eq!(parts(""), owned("", None, None, None));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Plain(Version);
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Structured(#[serde(with = "uapi_version::structured")] Version);
+
+    let json = |result: serde_json::Result<String>| {
+        result.unwrap_or_else(|e| panic!("Failed to serialize: {e}"))
+    };
+
+    let plain = Plain(Version::from("1.0-5"));
+    let plain_json = json(serde_json::to_string(&plain));
+    assert_eq!(plain_json, r#""1.0-5""#);
+    assert_eq!(serde_json::from_str::<Plain>(&plain_json).ok(), Some(plain));
+
+    for s in ["1.0-5", "1.0", "1.0~rc1^2-5", "1.0^2~rc1", "1.0~-a-b", ""] {
+        let structured = Structured(Version::from(s));
+        let structured_json = json(serde_json::to_string(&structured));
+        assert_eq!(
+            serde_json::from_str::<Structured>(&structured_json).ok(),
+            Some(structured),
+            "{s}"
+        );
+    }
+
+    assert_eq!(
+        json(serde_json::to_string(&Structured(Version::from("1.0-5")))),
+        r#"{"upstream":"1.0","release":"5"}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<Structured>(r#"{"upstream":"1.0","patch":"3"}"#).ok(),
+        Some(Structured(Version::from("1.0^3")))
+    );
+    assert!(serde_json::from_str::<Structured>(r#"{"release":"5"}"#).is_err());
+}
+
+#[test]
+fn collapse_separators() {
+    let collapse = |s: &str| Version::from(s).collapse_separators();
+
+    assert_eq!(collapse("1..2"), Version::from("1.2"));
+    assert_eq!(collapse("1~~2--3^^^4"), Version::from("1~2-3^4"));
+    assert_eq!(collapse("...."), Version::from("."));
+    assert_eq!(collapse("1.-.2"), Version::from("1.-.2"));
+    assert_eq!(collapse("1__2"), Version::from("1__2"));
+    assert_eq!(collapse(""), Version::from(""));
+
+    // Collapsing changes the meaning.
+    assert_smaller("1..2", "1.2");
+    assert_smaller("123..0", "123.0");
+    assert_eq!(
+        collapse("123..0").cmp(&Version::from("123.0")),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn compatible() {
+    let compatible = |a: &str, b: &str| Version::from(a).is_compatible_with(&Version::from(b));
+
+    assert!(compatible("2.5", "2.1"));
+    assert!(compatible("2", "2.1~rc1"));
+    assert!(compatible("02.0", "2-5"));
+    assert!(compatible("2rc1", "2.0"));
+
+    assert!(!compatible("2.5", "3.0"));
+    assert!(!compatible("2.5", "20.5"));
+    assert!(!compatible("1.0", "0.1"));
+
+    assert!(!compatible("abc", "abc"));
+    assert!(!compatible("v2.0", "2.0"));
+    assert!(!compatible("", "2.0"));
+    assert!(!compatible("", ""));
+}
+
+#[test]
+fn from_numbers() {
+    assert_eq!(Version::from(1u64), Version::from("1"));
+    assert_eq!(
+        Version::from(u64::MAX),
+        Version::from("18446744073709551615")
+    );
+    assert_eq!(Version::from((1, 2, 3)), Version::from("1.2.3"));
+    assert_eq!(Version::from((0, 0, 0)), Version::from("0.0.0"));
+
+    assert!(Version::from((1, 2, 3)) < Version::from((1, 2, 4)));
+    assert!(Version::from((1, 2, 10)) > Version::from((1, 2, 9)));
+    assert!(Version::from((2, 0, 0)) > Version::from((1, 99, 99)));
+    assert_eq!(
+        Version::from(10u64).cmp(&Version::from(9u64)),
+        Ordering::Greater
+    );
+    assert_eq!(Version::from((1, 2, 3)).version_tuple(), (1, 2, 3));
+}
+
+#[test]
+fn glob() {
+    let glob = |version: &str, pattern: &str| Version::from(version).matches_glob(pattern);
+
+    assert!(glob("1.2.3", "1.2.*"));
+    assert!(glob("1.2.99", "1.2.*"));
+    assert!(glob("1.2.3~rc1", "1.2.*"));
+    assert!(glob("1.02.3", "1.2.*"));
+    assert!(glob("1.2.3", "*.2.3"));
+    assert!(glob("1.2.3", "*.*.*"));
+    assert!(glob("1.2.3", "1.2.3"));
+    assert!(glob("1..3", "1.*.3"));
+    assert!(glob("1.2.3", "1.2.3*"));
+
+    assert!(!glob("1.3.0", "1.2.*"));
+    assert!(!glob("1.2", "1.2.*"));
+    assert!(!glob("1.2.3.4", "1.2.*"));
+    assert!(!glob("1.20.3", "1.2.*"));
+    assert!(!glob("1.2.3", "1.2.4*"));
+    assert!(!glob("1.2.3", "*"));
+}
+
+#[test]
+fn pad_trailing_zero() {
+    let options = CompareOptions {
+        pad_trailing_zero: true,
+        ..CompareOptions::default()
+    };
+    let cmp = |a, b| strverscmp_with(a, b, &options);
+
+    assert_eq!(cmp("1.2", "1.2.0"), Ordering::Equal);
+    assert_eq!(cmp("1.2.0", "1.2"), Ordering::Equal);
+    assert_eq!(cmp("1.2", "1.2.0.00"), Ordering::Equal);
+    assert_eq!(cmp("123", "123.0"), Ordering::Equal);
+    assert_eq!(cmp("1.2-1", "1.2.0-1"), Ordering::Equal);
+    assert_eq!(cmp("1.2~rc1", "1.2.0~rc1"), Ordering::Equal);
+
+    assert_eq!(cmp("1.2", "1.2.1"), Ordering::Less);
+    assert_eq!(cmp("1.2.0.1", "1.2"), Ordering::Greater);
+    assert_eq!(cmp("1.2", "1.2."), Ordering::Less);
+    assert_eq!(cmp("1.2", "1.2.0."), Ordering::Less);
+    assert_eq!(cmp("1.2", "1.2.0~rc1"), Ordering::Greater);
+    assert_eq!(cmp("1.2.0", "1.2.0~rc1"), Ordering::Greater);
+    assert_eq!(cmp("1.2", "1.2.0.1~rc1"), Ordering::Less);
+    assert_eq!(cmp("1.2", "1.2.0a"), Ordering::Less);
+    assert_eq!(cmp("1.2", "1.2~rc1"), Ordering::Greater);
+    assert_eq!(cmp("1.2", "1.2-0"), Ordering::Less);
+
+    for a in CORPUS {
+        for b in CORPUS {
+            for c in CORPUS {
+                if cmp(a, b) != Ordering::Greater && cmp(b, c) != Ordering::Greater {
+                    assert_ne!(cmp(a, c), Ordering::Greater, "{a} <= {b} <= {c}");
+                }
+            }
+        }
+    }
+
+    // The default keeps the specified behaviour.
+    assert_smaller("1.2", "1.2.0");
+    assert_smaller("123", "123.0");
+}
+
+#[test]
+fn shorten() {
+    let shorten = |s: &str| Version::from(s).shorten();
+
+    assert_eq!(shorten("1.2.0.0"), Version::from("1.2"));
+    assert_eq!(shorten("1.2.3.0"), Version::from("1.2.3"));
+    assert_eq!(shorten("1.2.00.."), Version::from("1.2"));
+    assert_eq!(shorten("1.0.2"), Version::from("1.0.2"));
+    assert_eq!(shorten("0.0"), Version::from("0"));
+    assert_eq!(shorten("1.0-1.0"), Version::from("1.0-1"));
+    assert_eq!(shorten("1.0~rc1"), Version::from("1.0~rc1"));
+    assert_eq!(shorten(""), Version::from(""));
+
+    // Shortening changes the meaning.
+    assert_smaller("1.2", "1.2.0.0");
+}
+
+#[test]
+fn strip_leading_zeros() {
+    let strip = |s: &str| Version::from(s).strip_leading_zeros();
+
+    assert_eq!(strip("1.00"), Version::from("1.0"));
+    assert_eq!(strip("0001"), Version::from("1"));
+    assert_eq!(strip("1.007~rc01-a09^000"), Version::from("1.7~rc1-a9^0"));
+    assert_eq!(strip("100.200"), Version::from("100.200"));
+    assert_eq!(strip("a00b_00"), Version::from("a0b_0"));
+    assert_eq!(strip(""), Version::from(""));
+
+    for s in CORPUS {
+        let version = Version::from(*s);
+        assert_eq!(
+            version.strip_leading_zeros().cmp(&version),
+            Ordering::Equal,
+            "{s}"
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn version_map() {
+    use uapi_version::VersionMap;
+
+    let mut map = VersionMap::new();
+    assert!(map.is_empty());
+
+    assert_eq!(map.insert(Version::from("0_"), 1), None);
+    assert_eq!(map.get(&Version::from("0")), Some(&1));
+    assert_eq!(map.get_str("0"), Some(&1));
+    assert_eq!(map.get_str("00"), Some(&1));
+    assert_eq!(map.get_str("0.0"), None);
+
+    assert_eq!(
+        map.get_key_value(&Version::from("0")),
+        Some((&Version::from("0_"), &1))
+    );
+
+    assert_eq!(map.insert(Version::from("0"), 2), Some(1));
+    assert_eq!(
+        map.get_key_value(&Version::from("0_")),
+        Some((&Version::from("0"), &2))
+    );
+    assert_eq!(map.insert(Version::from("1.0~rc1"), 3), None);
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get_str("1.0~rc_1"), Some(&3));
+
+    assert_eq!(map.remove(&Version::from("000")), Some(2));
+    assert_eq!(map.get_str("0_"), None);
+    assert_eq!(map.len(), 1);
+}
+
+#[test]
+fn leading_alpha() {
+    let leading_alpha = |s| Version::from(s).leading_alpha().map(String::from);
+
+    assert_eq!(leading_alpha("abc-5"), Some("abc".into()));
+    assert_eq!(leading_alpha("Linux6.1"), Some("Linux".into()));
+    assert_eq!(leading_alpha("_+abc.1"), Some("abc".into()));
+    assert_eq!(leading_alpha("αbc"), Some("bc".into()));
+
+    assert_eq!(leading_alpha("5.2"), None);
+    assert_eq!(leading_alpha("_5abc"), None);
+    assert_eq!(leading_alpha("~abc"), None);
+    assert_eq!(leading_alpha(""), None);
+}
+
+#[test]
+fn budget() {
+    assert_eq!(
+        strverscmp_with_budget("1.2.3", "1.2.4", 5),
+        Some(Ordering::Less)
+    );
+    assert_eq!(strverscmp_with_budget("1.2.3", "1.2.4", 4), None);
+    assert_eq!(
+        strverscmp_with_budget("2.0", "1.0", 1),
+        Some(Ordering::Greater)
+    );
+    assert_eq!(
+        strverscmp_with_budget("1.0", "1.0", 4),
+        Some(Ordering::Equal)
+    );
+    assert_eq!(strverscmp_with_budget("1.0", "1.0", 3), None);
+    assert_eq!(strverscmp_with_budget("1", "1.0", 2), Some(Ordering::Less));
+    assert_eq!(strverscmp_with_budget("", "", 1), Some(Ordering::Equal));
+    assert_eq!(strverscmp_with_budget("", "", 0), None);
+    assert_eq!(strverscmp_with_budget("1", "2", 0), None);
+
+    let long = "1.".repeat(10_000);
+    assert_eq!(strverscmp_with_budget(&long, &long, 100), None);
+    assert_eq!(
+        strverscmp_with_budget(&long, &long, usize::MAX),
+        Some(Ordering::Equal)
+    );
+}
+
+#[test]
+fn parse_list() {
+    let versions = |s: &[&str]| s.iter().copied().map(Version::from).collect::<Vec<_>>();
+
+    assert_eq!(
+        Version::parse_list("1.0, 2.0, 3.0", ','),
+        versions(&["1.0", "2.0", "3.0"])
+    );
+    assert_eq!(
+        Version::parse_list("1.0,2.0,", ','),
+        versions(&["1.0", "2.0"])
+    );
+    assert_eq!(
+        Version::parse_list(" ,1.0,, \t,2.0-1 ,", ','),
+        versions(&["1.0", "2.0-1"])
+    );
+    assert_eq!(
+        Version::parse_list("1.0 ; 1.1~rc1", ';'),
+        versions(&["1.0", "1.1~rc1"])
+    );
+    assert_eq!(
+        Version::parse_list("1.0, 2.0", ';'),
+        versions(&["1.0, 2.0"])
+    );
+    assert!(Version::parse_list("", ',').is_empty());
+    assert!(Version::parse_list(" , ,", ',').is_empty());
+}
+
+/// The length of a numerical run only decides between runs that end at the same token boundary.
+/// Expected results come from `systemd-analyze compare-versions`.
+#[test]
+fn numeric_run_boundaries() {
+    let cases = [
+        ("1.23", "12.3", Ordering::Less),
+        ("1-2", "12", Ordering::Less),
+        ("12", "1.2", Ordering::Greater),
+        ("1_2", "12", Ordering::Less),
+        ("1.2", "12", Ordering::Less),
+        ("12-3", "1.23", Ordering::Greater),
+        ("1~2", "12", Ordering::Less),
+        ("12^3", "1.23", Ordering::Greater),
+        ("1.023", "1.23", Ordering::Equal),
+        ("1a2", "12", Ordering::Less),
+        ("12a", "1a2", Ordering::Greater),
+        ("1.23", "1.3", Ordering::Greater),
+        ("123", "12.3", Ordering::Greater),
+        ("12.3", "12-3", Ordering::Greater),
+        ("1.2.3", "12.3", Ordering::Less),
+        ("99-1", "100", Ordering::Less),
+        ("9.99", "10", Ordering::Less),
+        ("1.0-10", "1.0-9", Ordering::Greater),
+        ("1-23", "12-3", Ordering::Less),
+        ("12.3-4", "1.23-4", Ordering::Greater),
+        ("1.2^3", "1.2.3", Ordering::Less),
+        ("00.1", "0.01", Ordering::Equal),
+        ("1.20", "1.2.0", Ordering::Greater),
+        ("2-1", "2.1", Ordering::Less),
+        ("12a", "12.a", Ordering::Greater),
+        ("a12", "a1.2", Ordering::Greater),
+    ];
+    assert_ordering_list(&cases);
+    for (a, b, ordering) in cases {
+        assert_ordering(b, a, ordering.reverse());
+    }
+}
+
+#[test]
+fn git_describe() {
+    let describe = |s| Version::from_git_describe(s);
+
+    assert_eq!(describe("v1.2.3-5-gabc"), Some(Version::from("1.2.3^5")));
+    assert_eq!(
+        describe("1.2.3-12-g1a2b3c4d"),
+        Some(Version::from("1.2.3^12"))
+    );
+    assert_eq!(describe("v1.2.3-0-gabc"), Some(Version::from("1.2.3")));
+    assert_eq!(describe("v1.2.3"), Some(Version::from("1.2.3")));
+    assert_eq!(
+        describe("v1.2-rc1-3-gabc"),
+        Some(Version::from("1.2-rc1^3"))
+    );
+    assert_eq!(describe("v1.2-5-gxyz"), Some(Version::from("1.2-5-gxyz")));
+
+    // The mark of `--dirty` is dropped like the hash.
+    assert_eq!(
+        describe("v1.2.3-5-gabc-dirty"),
+        Some(Version::from("1.2.3^5"))
+    );
+    assert_eq!(
+        describe("v1.2.3-0-gabc-dirty"),
+        Some(Version::from("1.2.3"))
+    );
+    assert_eq!(describe("v1.2.3-dirty"), Some(Version::from("1.2.3")));
+
+    assert_eq!(describe(""), None);
+    assert_eq!(describe("v"), None);
+    assert_eq!(describe("v-5-gabc"), None);
+    assert_eq!(describe("v-dirty"), None);
+
+    let version = |s| describe(s).unwrap_or_else(|| panic!("Failed to parse {s}"));
+    assert!(version("v1.2.3-5-gabc") > version("v1.2.3"));
+    assert!(version("v1.2.3-5-gabc") > version("v1.2.3-4-gfff"));
+    assert!(version("v1.2.3-10-gabc") > version("v1.2.3-9-gabc"));
+    assert!(version("v1.2.3-5-gabc") < version("v1.2.4"));
+    assert!(version("v1.2.3-5-gabc").is_equivalent(&version("v1.2.3-5-gdef")));
+}
+
+#[test]
+fn prerelease_primary() {
+    let primary = |s| Version::from(s).prerelease_primary().map(String::from);
+
+    assert_eq!(primary("1.0~rc1.git20240101"), Some("rc1".into()));
+    assert_eq!(primary("1.0~rc1.2.3-5"), Some("rc1".into()));
+    assert_eq!(primary("1.0~rc1"), Some("rc1".into()));
+    assert_eq!(primary("1.0~rc1^2"), Some("rc1".into()));
+    assert_eq!(primary("1.0~.git"), Some(String::new()));
+    assert_eq!(primary("1.0"), None);
+    assert_eq!(primary("1.0-1~rc1.2"), None);
+    assert_eq!(primary("1.0^2~rc1"), None);
+
+    // The noise still counts when comparing.
+    assert_smaller("1.0~rc1", "1.0~rc1.git20240101");
+}
+
+#[test]
+fn version_writer() -> std::fmt::Result {
+    use std::fmt::Write;
+
+    let mut writer = VersionWriter::new();
+    write!(writer, "{}.{}", 1, 2)?;
+    writer.write_char('.')?;
+    write!(writer, "{}", 3)?;
+    assert_eq!(writer.finish(), Version::from("1.2.3"));
+
+    let mut writer = VersionWriter::with_capacity(16);
+    write!(writer, "{}~rc{}", Version::from("2.0"), 1)?;
+    assert!(writer.finish() < Version::from("2.0"));
+
+    assert_eq!(VersionWriter::new().finish(), Version::from(""));
+    Ok(())
+}
+
+#[test]
+fn split_at() {
+    let version = Version::from("1.0-5^2");
+    assert_eq!(version.split_at('-'), ("1.0", Some("5^2")));
+    assert_eq!(version.split_at('^'), ("1.0-5", Some("2")));
+    assert_eq!(version.split_at('.'), ("1", Some("0-5^2")));
+    assert_eq!(version.split_at('~'), ("1.0-5^2", None));
+
+    let version = Version::from("1.0~rc1-2-3");
+    assert_eq!(version.split_at('~'), ("1.0", Some("rc1-2-3")));
+    assert_eq!(version.split_at('-'), ("1.0~rc1", Some("2-3")));
+
+    assert_eq!(Version::from("1.0-").split_at('-'), ("1.0", Some("")));
+    assert_eq!(Version::from("").split_at('.'), ("", None));
+}
+
+#[test]
+fn strip_arch_suffix() {
+    let strip = |s| Version::from(s).strip_arch_suffix(COMMON_ARCHES);
+
+    assert_eq!(strip("1.0-5.x86_64"), Version::from("1.0-5"));
+    assert_eq!(strip("1.0-5.fc40.aarch64"), Version::from("1.0-5.fc40"));
+    assert_eq!(strip("1.0.noarch"), Version::from("1.0"));
+    assert_eq!(strip("1.0-5.9"), Version::from("1.0-5.9"));
+    assert_eq!(strip("1.0-5.X86_64"), Version::from("1.0-5.X86_64"));
+    assert_eq!(strip("1.0-5_x86_64"), Version::from("1.0-5_x86_64"));
+    assert_eq!(strip("x86_64"), Version::from("x86_64"));
+
+    let custom = Version::from("2.0.myarch").strip_arch_suffix(&["myarch"]);
+    assert_eq!(custom, Version::from("2.0"));
+    assert_eq!(
+        Version::from("2.0.x86_64").strip_arch_suffix(&[]),
+        Version::from("2.0.x86_64")
+    );
+}
+
+#[test]
+fn relationships() {
+    let relationship = |a, b| relationship(&Version::from(a), &Version::from(b));
+
+    assert_eq!(
+        relationship("1.0-5", "1.0-7"),
+        Relationship::SameUpstreamNewer
+    );
+    assert_eq!(
+        relationship("1.0", "1.0-1"),
+        Relationship::SameUpstreamNewer
+    );
+    assert_eq!(
+        relationship("1.0-7", "1.0-5"),
+        Relationship::SameUpstreamOlder
+    );
+    assert_eq!(
+        relationship("1.0-5", "01.0-5"),
+        Relationship::SameUpstreamEqual
+    );
+    // Only the release parts are compared.
+    assert_eq!(
+        relationship("1.0~rc1-5", "1.0-5"),
+        Relationship::SameUpstreamEqual
+    );
+    assert_eq!(
+        relationship("1.0-5", "1.0^1-4"),
+        Relationship::SameUpstreamOlder
+    );
+    assert_eq!(
+        relationship("1.0~rc1", "1.0"),
+        Relationship::SameUpstreamEqual
+    );
+
+    assert_eq!(relationship("1.0", "2.0"), Relationship::DifferentUpstream);
+    assert_eq!(
+        relationship("1.0-5", "1.0.1-1"),
+        Relationship::DifferentUpstream
+    );
+    assert_eq!(relationship("2.0", "1.0"), Relationship::DifferentUpstream);
+}
+
+#[test]
+fn pad_to_components() {
+    let pad = |s, n| Version::from(s).pad_to_components(n);
+
+    assert_eq!(pad("1.2", 3), Version::from("1.2.0"));
+    assert_eq!(pad("1", 4), Version::from("1.0.0.0"));
+    assert_eq!(pad("1.2.3", 3), Version::from("1.2.3"));
+    assert_eq!(pad("1.2.3.4", 2), Version::from("1.2.3.4"));
+    assert_eq!(pad("1.2", 0), Version::from("1.2"));
+
+    assert_eq!(pad("1.2~rc1", 3), Version::from("1.2~rc1"));
+    assert_eq!(pad("1.2-5", 3), Version::from("1.2-5"));
+    assert_eq!(pad("1.2.", 4), Version::from("1.2."));
+    assert_eq!(pad("", 2), Version::from(""));
+
+    // Padding changes the meaning.
+    assert_smaller("1.2", "1.2.0");
+}
+
+#[test]
+fn floor_and_ceil() {
+    let sorted = ["1.0~rc1", "1.0", "1.5", "01.5", "2.0"].map(Version::from);
+    let floor_of = |s| floor(&sorted, &Version::from(s)).map(Version::as_str);
+    let ceil_of = |s| ceil(&sorted, &Version::from(s)).map(Version::as_str);
+
+    assert_eq!(floor_of("1.7"), Some("01.5"));
+    assert_eq!(ceil_of("1.7"), Some("2.0"));
+    assert_eq!(floor_of("1.5"), Some("01.5"));
+    assert_eq!(ceil_of("1.5"), Some("1.5"));
+    assert_eq!(floor_of("1.0"), Some("1.0"));
+    assert_eq!(ceil_of("1.0~rc2"), Some("1.0"));
+
+    assert_eq!(floor_of("0.9"), None);
+    assert_eq!(ceil_of("0.9"), Some("1.0~rc1"));
+    assert_eq!(floor_of("3.0"), Some("2.0"));
+    assert_eq!(ceil_of("3.0"), None);
+
+    assert_eq!(floor(&[], &Version::from("1.0")), None);
+    assert_eq!(ceil(&[], &Version::from("1.0")), None);
+}
+
+/// Separators competing at the same position, checked against `systemd-analyze
+/// compare-versions`.
+#[test]
+fn separator_precedence_matrix() {
+    let versions = [
+        "1~", "1~1", "1", "1-", "1-1", "1^", "1^1", "1.", "1.1", "1a", "12",
+    ];
+    for (i, first) in versions.iter().enumerate() {
+        assert_ordering(first, first, Ordering::Equal);
+        for next in versions.iter().skip(i + 1) {
+            assert_smaller(first, next);
+            assert_ordering(next, first, Ordering::Greater);
+        }
+    }
+
+    let separated = ["1~2", "1", "1-2", "1^2", "1.2", "1a2", "12"];
+    for (i, first) in separated.iter().enumerate() {
+        for next in separated.iter().skip(i + 1) {
+            assert_smaller(first, next);
+        }
+    }
+
+    // Each separator is compared on its own, so after a shared separator the end of a version
+    // still sorts above `~` and below everything else. systemd doesn't check for the end again
+    // right after a shared separator and orders these the other way round.
+    assert_smaller_list(&[("~~", "~"), ("1-", "1-^"), ("1^", "1^.")]);
+}
+
+#[test]
+fn map_numeric() {
+    let map = |s, f: fn(u64) -> u64| Version::from(s).map_numeric(f);
+
+    assert_eq!(map("1.2.3", |n| n + 1), Some(Version::from("2.3.4")));
+    assert_eq!(
+        map("01.a2_3-04", |n| n * 10),
+        Some(Version::from("10.a20_30-40"))
+    );
+    assert_eq!(map("1.20.3", |n| n.min(9)), Some(Version::from("1.9.3")));
+    assert_eq!(map("abc", |n| n + 1), Some(Version::from("abc")));
+    assert_eq!(map("", |n| n + 1), Some(Version::from("")));
+    assert_eq!(map("٢1", |n| n + 1), Some(Version::from("٢2")));
+
+    assert_eq!(map("1.18446744073709551616", |n| n), None);
+    assert_eq!(
+        map("18446744073709551615", |n| n),
+        Some(Version::from("18446744073709551615"))
+    );
+}
+
+#[test]
+fn revision_after() {
+    let after = |a: &str, b: &str| Version::from(a).is_revision_after(&Version::from(b));
+
+    assert!(after("1.0-7", "1.0-5"));
+    assert!(after("1.0-5.1", "1.0-5"));
+    assert!(after("1.0-1", "1.0"));
+    assert!(after("01.0-10", "1.0-9"));
+
+    assert!(!after("1.1-7", "1.0-5"));
+    assert!(!after("1.0-5", "1.0-5"));
+    assert!(!after("1.0-5", "1.0-7"));
+    assert!(!after("1.0", "1.0-1"));
+    assert!(!after("1.0", "1.0"));
+    assert!(!after("1.0.1-1", "1.0-5"));
+}
+
+#[test]
+fn epoch() {
+    let version = Version::from("2:1.0");
+    assert_eq!(version.epoch(), Some(2));
+    assert_eq!(version.without_epoch(), "1.0");
+
+    let version = Version::from("1.0");
+    assert_eq!(version.epoch(), None);
+    assert_eq!(version.without_epoch(), "1.0");
+
+    assert_eq!(Version::from("0:1.0-1").epoch(), Some(0));
+    assert_eq!(Version::from("10:").without_epoch(), "");
+    assert_eq!(Version::from(":1.0").epoch(), None);
+    assert_eq!(Version::from(":1.0").without_epoch(), ":1.0");
+    assert_eq!(Version::from("1.0:2").epoch(), None);
+    assert_eq!(Version::from("a:1.0").without_epoch(), "a:1.0");
+
+    let overflowing = Version::from("99999999999999999999:1.0");
+    assert_eq!(overflowing.epoch(), None);
+    assert_eq!(overflowing.without_epoch(), "1.0");
+}
+
+#[test]
+fn patch_update() {
+    let update = |a: &str, b: &str| Version::from(a).is_patch_update_of(&Version::from(b));
+
+    assert!(update("123^aa2-67.89", "123^aa1-99.99"));
+    assert!(update("123^aa1", "123^aa1-99.99"));
+    assert!(update("123^45-67.89", "123-67.89"));
+    assert!(update("123^1", "123"));
+    assert!(update("123", "123"));
+
+    assert!(!update("123^aa1-99.99", "123^aa2-67.89"));
+    assert!(!update("123", "123^1"));
+    assert!(!update("123.45-67.89", "123^45-67.89"));
+    assert!(!update("123aa2-67.89", "123^aa2-67.89"));
+    assert!(!update("124^aa2", "123^aa1"));
+}
+
+#[test]
+fn parsed_version() {
+    for a in CORPUS.iter().copied().map(Version::from) {
+        let parsed_a = ParsedVersion::from(a.clone());
+        for b in CORPUS.iter().copied().map(Version::from) {
+            let parsed_b = ParsedVersion::from(b.clone());
+            let expected = a.cmp(&b);
+            assert_eq!(parsed_a.cmp(&parsed_b), expected, "{a} vs {b}");
+            assert_eq!(parsed_a.partial_cmp(&b), Some(expected), "{a} vs {b}");
+            assert_eq!(a.partial_cmp(&parsed_b), Some(expected), "{a} vs {b}");
+            assert_eq!(parsed_a == b, a == b, "{a} vs {b}");
+            assert_eq!(a == parsed_b, a == b, "{a} vs {b}");
+        }
+    }
+
+    let parsed = ParsedVersion::from("1_");
+    assert_ne!(parsed, Version::from("1"));
+    assert_eq!(
+        parsed.partial_cmp(&Version::from("1")),
+        Some(Ordering::Equal)
+    );
+    assert_eq!(parsed.into_version(), Version::from("1_"));
+}
+
+#[test]
+fn segment_spans() {
+    let version = Version::from("1.0~rc1");
+    let spans: Vec<_> = version
+        .segment_spans()
+        .into_iter()
+        .map(|(range, kind)| (&version.as_str()[range], kind))
+        .collect();
+    assert_eq!(
+        spans,
+        [
+            ("1", VersionTokenKind::Numeric),
+            (".", VersionTokenKind::Dot),
+            ("0", VersionTokenKind::Numeric),
+            ("~", VersionTokenKind::Tilde),
+            ("rc", VersionTokenKind::Alpha),
+            ("1", VersionTokenKind::Numeric),
+        ]
+    );
+
+    let version = Version::from("ä1__2+");
+    assert_eq!(
+        version.segment_spans(),
+        [
+            (2..3, VersionTokenKind::Numeric),
+            (5..6, VersionTokenKind::Numeric),
+        ]
+    );
+
+    for version in CORPUS.iter().copied().map(Version::from) {
+        let spans = version.segment_spans();
+        assert_eq!(spans.len(), version.tokens().count());
+        for ((range, kind), token) in spans.into_iter().zip(version.tokens()) {
+            assert_eq!(&version.as_str()[range], token.as_str());
+            assert_eq!(kind, token.kind());
+        }
+    }
+}
+
+#[test]
+fn sort_key() {
+    let long = "9".repeat(300);
+    let longer = "1".repeat(301);
+    let mut versions: Vec<Version> = CORPUS
+        .iter()
+        .copied()
+        .chain([long.as_str(), longer.as_str(), "ab", "abc", "a.b"])
+        .map(Version::from)
+        .collect();
+
+    for a in &versions {
+        for b in &versions {
+            assert_eq!(a.sort_key().cmp(&b.sort_key()), a.cmp(b), "{a} vs {b}");
+        }
+    }
+
+    let mut expected = versions.clone();
+    expected.sort();
+    versions.sort_by_cached_key(Version::sort_key);
+    assert_eq!(versions, expected);
+}
+
+#[test]
+fn well_formed() {
+    for version in ["1.2.3", "1", "a", "1.0~rc1-2^3", "1..2", "-1", "1~", "1_2"] {
+        assert!(Version::from(version).is_well_formed(), "{version}");
+    }
+    for version in ["~-^.", "", "...", "_", ".1.2", "1.2.", "."] {
+        assert!(!Version::from(version).is_well_formed(), "{version}");
+    }
+}
+
+#[test]
+fn classified_components() {
+    assert_eq!(
+        Version::from("1.rc2.0").classified_components(),
+        [
+            ("1", ComponentKind::Numeric),
+            ("rc2", ComponentKind::Mixed),
+            ("0", ComponentKind::Numeric),
+        ]
+    );
+    assert_eq!(
+        Version::from("007.beta.45-67.").classified_components(),
+        [
+            ("007", ComponentKind::Numeric),
+            ("beta", ComponentKind::Alpha),
+            ("45-67", ComponentKind::Mixed),
+            ("", ComponentKind::Mixed),
+        ]
+    );
+    assert_eq!(
+        Version::from("").classified_components(),
+        [("", ComponentKind::Mixed)]
+    );
+}
+
+#[test]
+fn symbolic_version() {
+    for keyword in ["latest", "LATEST", "Latest"] {
+        assert_eq!(SymbolicVersion::from(keyword), SymbolicVersion::Latest);
+    }
+    for keyword in ["stable", "STABLE", "sTaBlE"] {
+        assert_eq!(SymbolicVersion::from(keyword), SymbolicVersion::Stable);
+    }
+    assert_eq!(
+        SymbolicVersion::from("latest1"),
+        SymbolicVersion::Concrete(Version::from("latest1"))
+    );
+
+    for version in CORPUS.iter().copied().map(SymbolicVersion::from) {
+        assert!(SymbolicVersion::Latest > version, "{version}");
+        assert!(SymbolicVersion::Stable > version, "{version}");
+    }
+    assert!(SymbolicVersion::Latest > SymbolicVersion::Stable);
+    assert!(SymbolicVersion::from("1.10") > SymbolicVersion::from("1.9"));
+
+    let available = ["1.0", "2.0~rc1", "1.5", "1.05"].map(Version::from);
+    assert_eq!(
+        SymbolicVersion::Latest.resolve(&available),
+        Some(&available[1])
+    );
+    assert_eq!(
+        SymbolicVersion::Stable.resolve(&available),
+        Some(&available[2])
+    );
+    assert_eq!(
+        SymbolicVersion::from("1.005").resolve(&available),
+        Some(&available[2])
+    );
+    assert_eq!(SymbolicVersion::from("3").resolve(&available), None);
+    assert_eq!(SymbolicVersion::Latest.resolve(&[]), None);
+    assert_eq!(SymbolicVersion::Stable.to_string(), "stable");
+}
+
+#[test]
+fn segment_count() {
+    let count = |s: &str| {
+        let version = Version::from(s);
+        (version.segment_count(), version.numeric_segment_count())
+    };
+
+    assert_eq!(count("1.0~rc1"), (6, 3));
+    assert_eq!(count("1_2"), (2, 2));
+    assert_eq!(count("rc"), (1, 0));
+    assert_eq!(count("..."), (3, 0));
+    assert_eq!(count(""), (0, 0));
+}
+
+#[test]
+fn validate() {
+    let report = Version::validate("12_3");
+    assert_eq!(report.invalid_chars, [2]);
+    assert!(report.is_well_formed);
+    assert_eq!(report.normalized.as_str(), "12_3");
+
+    let report = Version::validate("1.2.3");
+    assert!(report.invalid_chars.is_empty());
+    assert!(report.is_well_formed);
+    assert_eq!(report.normalized, Version::from("1.2.3").normalized());
+
+    let report = Version::validate(" 01.ä.");
+    assert_eq!(report.invalid_chars, [0, 4]);
+    assert!(!report.is_well_formed);
+    assert_eq!(report.normalized.as_str(), "1..");
+}
+
+#[test]
+fn downgrade() {
+    let downgrade = |from: &str, to: &str| is_downgrade(&Version::from(from), &Version::from(to));
+
+    assert!(downgrade("2.0", "1.9"));
+    assert!(downgrade("1.10", "1.9"));
+    assert!(downgrade("1.0-2", "1.0-1"));
+    assert!(!downgrade("1.9", "2.0"));
+    assert!(!downgrade("1.0", "1.0"));
+    assert!(!downgrade("1.0", "1.00"));
+
+    assert!(downgrade("1.0", "1.0~rc1"));
+    assert!(downgrade("1.0~rc2", "1.0~rc1"));
+    assert!(!downgrade("1.0~rc1", "1.0"));
+    assert!(!downgrade("0.9", "1.0~rc1"));
+}
+
+#[test]
+fn common_prefix() {
+    let suffix = |a: &str, b: &str| {
+        let (a, b) = (Version::from(a), Version::from(b));
+        (
+            a.common_prefix(&b).to_string(),
+            a.suffix_after_common(&b).to_string(),
+        )
+    };
+
+    assert_eq!(suffix("1.2.3", "1.2.9"), ("1.2".into(), "3".into()));
+    assert_eq!(suffix("1.2.9", "1.2.3"), ("1.2".into(), "9".into()));
+    assert_eq!(suffix("1.10", "1.19"), ("1".into(), "10".into()));
+    assert_eq!(suffix("01.2-3", "1.2-4"), ("01".into(), "2-3".into()));
+    assert_eq!(suffix("2.0", "1.0"), (String::new(), "2.0".into()));
+
+    // One is a prefix of the other.
+    assert_eq!(suffix("1.2", "1.2.3"), ("1.2".into(), String::new()));
+    assert_eq!(suffix("1.2.3", "1.2"), ("1.2".into(), "3".into()));
+    assert_eq!(suffix("1.2", "1.2"), ("1.2".into(), String::new()));
+    assert_eq!(suffix("", "1"), (String::new(), String::new()));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn from_os_str() {
+    use std::ffi::OsStr;
+
+    assert_eq!(
+        Version::from_os_str(OsStr::new("6.1.0-rc2")),
+        Some(Version::from("6.1.0-rc2"))
+    );
+    assert_eq!(
+        Version::from_os_str(OsStr::new("")),
+        Some(Version::from(""))
+    );
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+
+        assert_eq!(Version::from_os_str(OsStr::from_bytes(b"1.\xff")), None);
+    }
+}
+
+#[test]
+fn is_zero() {
+    for version in ["0", "0.0", "0.0.0", "00.000", "0_", "_0_", "0___", "0..0"] {
+        assert!(Version::from(version).is_zero(), "{version}");
+    }
+    for version in [
+        "0.1", "1", "10", "", "_", ".", "0~rc0", "0-0", "0^0", "0a", "a0",
+    ] {
+        assert!(!Version::from(version).is_zero(), "{version}");
+    }
+}
+
+#[test]
+fn as_ref_inputs() {
+    use std::borrow::Cow;
+
+    let owned: [Cow<str>; 2] = [Cow::Owned("1.10".into()), Cow::Owned("1.9".into())];
+    assert_eq!(strverscmp_as_ref(&owned[0], &owned[1]), Ordering::Greater);
+
+    let borrowed: [Cow<str>; 2] = [Cow::Borrowed("1.0~rc1"), Cow::Borrowed("1.0")];
+    assert_eq!(
+        strverscmp_as_ref(&borrowed[0], &borrowed[1]),
+        Ordering::Less
+    );
+
+    assert_eq!(strverscmp_as_ref(&owned[1], "1.09"), Ordering::Equal);
+    assert_eq!(
+        strverscmp_as_ref(String::from("2"), &borrowed[1]),
+        Ordering::Greater
+    );
+
+    // strverscmp itself keeps taking `&str`, so it still coerces to a function pointer.
+    let cmp: fn(&str, &str) -> Ordering = strverscmp;
+    let mut versions = vec!["1.10", "1.9", "1.0~rc1"];
+    versions.sort_by(|a, b| cmp(a, b));
+    assert_eq!(versions, ["1.0~rc1", "1.9", "1.10"]);
+}
+
+#[test]
+fn ensure_newer_versions() {
+    let previous = Version::from("1.0");
+
+    assert_eq!(ensure_newer(&previous, &Version::from("1.1")), Ok(()));
+    assert_eq!(ensure_newer(&previous, &Version::from("1.0^1")), Ok(()));
+
+    for candidate in ["1.0", "1.00", "0.9", "1.0~rc1"] {
+        let candidate = Version::from(candidate);
+        assert_eq!(
+            ensure_newer(&previous, &candidate),
+            Err(DowngradeError {
+                previous: previous.clone(),
+                candidate: candidate.clone(),
+            })
+        );
+    }
+
+    let Err(error) = ensure_newer(&previous, &Version::from("0.9")) else {
+        panic!("0.9 is not newer than 1.0");
+    };
+    assert_eq!(error.to_string(), "version 0.9 is not newer than 1.0");
+}
+
+#[test]
+fn numeric_only() {
+    for (version, expected) in [
+        ("1.2rc1-3", "1.2"),
+        ("1.2.3", "1.2.3"),
+        ("1.2-3.4", "1.2"),
+        ("1.0~rc1", "1.0"),
+        ("01.002", "01.002"),
+        ("1.rc.3", "1"),
+        ("abc", ""),
+        ("", ""),
+    ] {
+        assert_eq!(
+            Version::from(version).numeric_only(),
+            Version::from(expected),
+            "{version}"
+        );
+    }
+}
+
+#[test]
+fn contains_component() {
+    let version = Version::from("1.02.3-rc1.el9");
+
+    for value in ["1", "2", "002", "3-rc1", "3-rc01", "el9", "el09"] {
+        assert!(version.contains_component(value), "{value}");
+    }
+    for value in ["0", "3", "rc1", "el", "1.02", ""] {
+        assert!(!version.contains_component(value), "{value}");
+    }
+    assert!(Version::from("1..2").contains_component(""));
+}
+
+/// Letters followed by digits are split into an alphabetical and a numerical segment, so the
+/// digits compare by value once the letters are equal. Expected results match
+/// `systemd-analyze compare-versions`.
+#[test]
+fn alpha_with_numeric_tail() {
+    assert_smaller_list(&[
+        ("rc9", "rc10"),
+        ("beta9", "beta10"),
+        ("a9", "a10"),
+        ("1.0rc9", "1.0rc10"),
+        ("1.0~rc9", "1.0~rc10"),
+        ("rc9a", "rc10"),
+        ("rc9b", "rc10a"),
+        ("rc1", "rc1a"),
+        // The letters decide before the digits are looked at.
+        ("a9", "b1"),
+        ("beta10", "rc1"),
+    ]);
+    assert_ordering_list(&[("rc09", "rc9", Ordering::Equal)]);
+}
+
+#[test]
+fn binary_heap() {
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    let newest_first = ["2.0", "1.10", "1.9", "1.0", "1.0~rc1"];
+    let shuffled = ["1.0", "1.10", "1.0~rc1", "2.0", "1.9"];
+
+    let mut heap: BinaryHeap<Version> = shuffled.into_iter().map(Version::from).collect();
+    let popped: Vec<_> = core::iter::from_fn(|| heap.pop()).collect();
+    assert_eq!(popped, newest_first.map(Version::from));
+
+    let mut heap: BinaryHeap<Reverse<Version>> = shuffled
+        .into_iter()
+        .map(|v| Reverse(Version::from(v)))
+        .collect();
+    let popped: Vec<_> = core::iter::from_fn(|| heap.pop().map(|v| v.0)).collect();
+    assert!(popped
+        .iter()
+        .eq(newest_first.map(Version::from).iter().rev()));
+
+    let mut heap: BinaryHeap<OldestFirst> = shuffled
+        .into_iter()
+        .map(|v| OldestFirst(Version::from(v)))
+        .collect();
+    let popped: Vec<_> = core::iter::from_fn(|| heap.pop().map(|v| v.0)).collect();
+    assert!(popped
+        .iter()
+        .eq(newest_first.map(Version::from).iter().rev()));
+
+    for a in CORPUS {
+        for b in CORPUS {
+            assert_eq!(
+                OldestFirst(Version::from(*a)).cmp(&OldestFirst(Version::from(*b))),
+                strverscmp(a, b).reverse(),
+                "{a} vs {b}"
+            );
+        }
+    }
+}
+
+#[test]
+fn kernel_versions() {
+    for (kver, expected) in [
+        ("6.1.0-rc2", "6.1.0~rc2"),
+        ("6.1.0-rc2-arch1-1", "6.1.0~rc2-arch1-1"),
+        ("6.1.0-arch1-1", "6.1.0-arch1-1"),
+        ("6.1.0-rcfoo", "6.1.0-rcfoo"),
+        ("6.1.0-rc2x", "6.1.0-rc2x"),
+        ("6.1.0-1-rc2", "6.1.0-1-rc2"),
+        ("6.1.0", "6.1.0"),
+    ] {
+        assert_eq!(Version::from_kver(kver).as_str(), expected, "{kver}");
+    }
+
+    let kernels = [
+        "5.19.0",
+        "6.1.0-rc1",
+        "6.1.0-rc2",
+        "6.1.0-rc10",
+        "6.1.0",
+        "6.1.0-arch1-1",
+        "6.1.0-arch1-2",
+        "6.1.0-arch2-1",
+        "6.1.1-arch1-1",
+        "6.10.0-rc1",
+    ]
+    .map(Version::from_kver);
+    for pair in kernels.windows(2) {
+        assert!(pair[0] < pair[1], "{} < {}", pair[0], pair[1]);
+    }
+
+    let version = Version::from_kver("6.1.0-rc2-arch1-1");
+    assert_eq!(version.upstream(), "6.1.0");
+    assert_eq!(version.prerelease_tag(), Some("rc2"));
+    assert_eq!(version.release(), Some("arch1-1"));
+}
+
+#[test]
+fn distance() {
+    let distance = |a: &str, b: &str| {
+        let distance = Version::from(a).distance(&Version::from(b));
+        (distance.component_index, distance.numeric_delta)
+    };
+
+    assert_eq!(distance("1.2.3", "1.2.9"), (2, Some(6)));
+    assert_eq!(distance("1.2.9", "1.2.3"), (2, Some(-6)));
+    assert_eq!(distance("1.2.3", "2.0.0"), (0, Some(1)));
+    assert_eq!(distance("1.2.3", "1.2.3rc1"), (2, None));
+    assert_eq!(distance("1.2", "1.2.1"), (2, None));
+    assert_eq!(distance("0", "18446744073709551615"), (0, None));
+
+    // Equal versions differ one past their last component.
+    assert_eq!(distance("1.2.3", "1.02.03"), (3, None));
+    assert_eq!(distance("1.2.3", "1.2.3"), (3, None));
+    assert_eq!(distance("", ""), (1, None));
+
+    assert_eq!(
+        Version::from("1.0").distance(&Version::from("1.5")),
+        VersionDistance {
+            component_index: 1,
+            numeric_delta: Some(5),
+        }
+    );
+}
+
+#[test]
+fn shortest_unique_prefixes_of_versions() {
+    let prefixes = |versions: &[&str]| {
+        shortest_unique_prefixes(
+            &versions
+                .iter()
+                .copied()
+                .map(Version::from)
+                .collect::<Vec<_>>(),
+        )
+    };
+
+    assert_eq!(
+        prefixes(&["1.2.3", "1.2.4", "2.0"]),
+        ["1.2.3", "1.2.4", "2"]
+    );
+    assert_eq!(
+        prefixes(&["1.2.3", "1.3.0", "1.3.1-2"]),
+        ["1.2", "1.3.0", "1.3.1-2"]
+    );
+    assert_eq!(prefixes(&["1.2", "1.2.3"]), ["1.2", "1.2.3"]);
+    assert_eq!(prefixes(&["1.2", "1.2", "3"]), ["1.2", "1.2", "3"]);
+    assert_eq!(prefixes(&["01.5", "1.6"]), ["01.5", "1.6"]);
+    assert_eq!(prefixes(&["1.2.3"]), ["1"]);
+    assert!(prefixes(&[]).is_empty());
+}
+
+#[test]
+fn with_prerelease() {
+    let with = |version: &str, tag: &str| {
+        Version::from(version)
+            .with_prerelease(tag)
+            .map(Version::into_string)
+    };
+
+    assert_eq!(with("1.0-5", "rc1").as_deref(), Some("1.0~rc1-5"));
+    assert_eq!(with("1.0", "rc1").as_deref(), Some("1.0~rc1"));
+    assert_eq!(with("1.0^2-5", "beta.2").as_deref(), Some("1.0~beta.2^2-5"));
+    assert_eq!(with("1.0~rc1-5", "rc2").as_deref(), Some("1.0~rc2-5"));
+    assert_eq!(with("1.0~rc1^2", "rc2").as_deref(), Some("1.0~rc2^2"));
+    assert_eq!(with("", "rc1").as_deref(), Some("~rc1"));
+
+    for tag in ["", "rc 1", "rc-1", "rc^1", "rc_1"] {
+        assert_eq!(with("1.0", tag), None, "{tag}");
+    }
+
+    let version = Version::from("1.0-5");
+    let Some(prerelease) = version.with_prerelease("rc1") else {
+        panic!("rc1 is a valid tag");
+    };
+    assert!(prerelease < version);
+    assert_eq!(prerelease.prerelease_tag(), Some("rc1"));
+    assert_eq!(prerelease.strip_prerelease(), version);
+}
+
+#[test]
+fn clear_prerelease() {
+    let clear = |s: &str| Version::from(s).clear_prerelease().into_string();
+
+    assert_eq!(clear("1.0~rc1"), "1.0");
+    assert_eq!(clear("1.0~rc1-5"), "1.0-5");
+    assert_eq!(clear("1.0~beta.2^2-5"), "1.0^2-5");
+    assert_eq!(clear("1.0-5~rc1"), "1.0-5~rc1");
+    assert_eq!(clear("1.0^2~rc1"), "1.0^2~rc1");
+    assert_eq!(clear("1.0-5"), "1.0-5");
+    assert_eq!(clear(""), "");
+
+    for (version, tag) in [("1.0-5", "rc1"), ("1.0^2", "beta.2"), ("", "rc1")] {
+        let version = Version::from(version);
+        let Some(prerelease) = version.with_prerelease(tag) else {
+            panic!("{tag} is a valid tag");
+        };
+        assert_eq!(prerelease.clear_prerelease(), version);
+    }
+}
+
+#[test]
+fn strict() {
+    assert_eq!(strverscmp_strict("12", "1"), Ok(Ordering::Greater));
+    assert_eq!(strverscmp_strict("1.0~rc1", "1.0"), Ok(Ordering::Less));
+    assert_eq!(strverscmp_strict("1_ä", "1"), Ok(Ordering::Equal));
+
+    assert_eq!(
+        strverscmp_strict("1٢", "1"),
+        Err(StrictError::NonAsciiDigit { index: 1 })
+    );
+    // The offset is into `b` if `a` is fine.
+    assert_eq!(
+        strverscmp_strict("12", "1.๑"),
+        Err(StrictError::NonAsciiDigit { index: 2 })
+    );
+    assert_eq!(
+        strverscmp_strict("1.๑", "12"),
+        Err(StrictError::NonAsciiDigit { index: 2 })
+    );
+    assert_eq!(
+        strverscmp_strict("1²", "1٢"),
+        Err(StrictError::NonAsciiDigit { index: 1 })
+    );
+    // Numeric characters that aren't digits are rejected too.
+    assert_eq!(
+        strverscmp_strict("1½", "1"),
+        Err(StrictError::NonAsciiDigit { index: 1 })
+    );
+    assert_eq!(
+        strverscmp_strict("1.0", "Ⅻ"),
+        Err(StrictError::NonAsciiDigit { index: 0 })
+    );
+    assert_eq!(
+        StrictError::NonAsciiDigit { index: 1 }.to_string(),
+        "non-ASCII digit at byte offset 1"
+    );
+}
+
+#[test]
+fn adjacent() {
+    let adjacent = |a: &str, b: &str| {
+        let (a, b) = (Version::from(a), Version::from(b));
+        assert_eq!(a.is_adjacent_to(&b), b.is_adjacent_to(&a), "{a} vs {b}");
+        a.is_adjacent_to(&b)
+    };
+
+    assert!(adjacent("1.2.3", "1.2.4"));
+    assert!(adjacent("1.2.4", "1.2.3"));
+    assert!(adjacent("1.02.9", "1.2.10"));
+    assert!(adjacent("0", "1"));
+
+    assert!(!adjacent("1.2.3", "1.2.5"));
+    assert!(!adjacent("1.2.3", "1.2.3"));
+    assert!(!adjacent("1.2.3", "1.3.4"));
+    assert!(!adjacent("1.2", "1.2.1"));
+    assert!(!adjacent("1.2.rc1", "1.2.rc2"));
+    assert!(!adjacent("1.2.3a", "1.2.4"));
+}
+
+#[test]
+fn leading_number() {
+    let leading = |s: &str| {
+        let version = Version::from(s);
+        (version.leading_number(), version.leading_number_width())
+    };
+
+    assert_eq!(leading("007"), (Some(7), Some(3)));
+    assert_eq!(leading("7"), (Some(7), Some(1)));
+    assert_eq!(leading("0.1"), (Some(0), Some(1)));
+    assert_eq!(leading("_012rc1"), (Some(12), Some(3)));
+    assert_eq!(leading("abc-5"), (None, None));
+    assert_eq!(leading("~1"), (None, None));
+    assert_eq!(leading(""), (None, None));
+    assert_eq!(leading("99999999999999999999.1"), (None, Some(20)));
+}
+
+#[test]
+fn observed() {
+    let record = |a, b| {
+        let mut steps = Vec::new();
+        let ordering = strverscmp_observed(a, b, &mut |pair: SegmentPair| {
+            steps.push((
+                pair.left.map(|t| t.as_str()),
+                pair.right.map(|t| t.as_str()),
+                pair.ordering,
+            ));
+        });
+        (ordering, steps)
+    };
+
+    assert_eq!(
+        record("1.2", "1.3"),
+        (
+            Ordering::Less,
+            vec![
+                (Some("1"), Some("1"), Ordering::Equal),
+                (Some("."), Some("."), Ordering::Equal),
+                (Some("2"), Some("3"), Ordering::Less),
+            ]
+        )
+    );
+    assert_eq!(
+        record("01", "1_"),
+        (
+            Ordering::Equal,
+            vec![
+                (Some("01"), Some("1"), Ordering::Equal),
+                (None, None, Ordering::Equal),
+            ]
+        )
+    );
+    assert_eq!(
+        record("1.0", "1.0~rc1"),
+        (
+            Ordering::Greater,
+            vec![
+                (Some("1"), Some("1"), Ordering::Equal),
+                (Some("."), Some("."), Ordering::Equal),
+                (Some("0"), Some("0"), Ordering::Equal),
+                (None, Some("~"), Ordering::Greater),
+            ]
+        )
+    );
+
+    for a in CORPUS {
+        for b in CORPUS {
+            let mut last = None;
+            let ordering = strverscmp_observed(a, b, &mut |pair| last = Some(pair.ordering));
+            assert_eq!(ordering, strverscmp(a, b), "{a} vs {b}");
+            assert_eq!(last, Some(ordering), "{a} vs {b}");
+        }
+    }
+}
+
+#[test]
+fn trailing_number() {
+    for (version, expected) in [
+        ("1.2-45", Some(45)),
+        ("1.2-045", Some(45)),
+        ("1.2-45_", Some(45)),
+        ("7", Some(7)),
+        ("1.0~rc0", Some(0)),
+        ("1.2rc", None),
+        ("1.2.", None),
+        ("1.99999999999999999999", None),
+        ("", None),
+    ] {
+        assert_eq!(
+            Version::from(version).trailing_number(),
+            expected,
+            "{version}"
+        );
+    }
+}
+
+#[test]
+fn same_series() {
+    let same = |a: &str, b: &str, depth| Version::from(a).same_series(&Version::from(b), depth);
+
+    assert!(same("5.2.1", "5.2.9", 2));
+    assert!(same("5.2.1", "5.2.9", 1));
+    assert!(!same("5.2.1", "5.2.9", 3));
+    assert!(!same("5.2.1", "5.3.0", 2));
+    assert!(same("05.2", "5.02", 2));
+
+    // Components are compared as a whole, not as string prefixes.
+    assert!(same("5.2", "5.20", 1));
+    assert!(!same("5.2", "5.20", 2));
+
+    // Missing components never match.
+    assert!(same("5.2", "5.2", 2));
+    assert!(!same("5.2", "5.2", 3));
+    assert!(!same("5", "5.0", 2));
+    assert!(same("1", "2", 0));
+}
+
+#[test]
+fn format_with() {
+    let all_dots = SeparatorStyle {
+        tilde: ".",
+        ..SeparatorStyle::DOTTED
+    };
+    assert_eq!(Version::from("1.0-5^2").format_with(&all_dots), "1.0.5.2");
+    assert_eq!(
+        Version::from("1.0-5^2").format_with(&SeparatorStyle::DOTTED),
+        "1.0.5.2"
+    );
+    assert_eq!(
+        Version::from("1.0~rc1-5^2").format_with(&SeparatorStyle::DOTTED),
+        "1.0~rc1.5.2"
+    );
+
+    let debian = SeparatorStyle {
+        caret: "+",
+        ..SeparatorStyle::default()
+    };
+    assert_eq!(Version::from("1.0^2-5").format_with(&debian), "1.0+2-5");
+
+    let condensed = SeparatorStyle {
+        dot: "",
+        ..SeparatorStyle::UAPI
+    };
+    assert_eq!(Version::from("1.2.3_x").format_with(&condensed), "123_x");
+
+    for version in CORPUS.iter().copied().map(Version::from) {
+        assert_eq!(version.format_with(&SeparatorStyle::UAPI), version.as_str());
+    }
+}
+
+#[test]
+fn update_kinds() {
+    let kind = |from: &str, to: &str| update_kind(&Version::from(from), &Version::from(to));
+
+    assert_eq!(kind("1.2.3", "2.0.0"), UpdateKind::Major);
+    assert_eq!(kind("1.2.3", "2"), UpdateKind::Major);
+    assert_eq!(kind("1.2.3", "1.3.0"), UpdateKind::Minor);
+    assert_eq!(kind("1.2", "1.2.1"), UpdateKind::Patch);
+    assert_eq!(kind("1.2.3", "1.2.4-1"), UpdateKind::Patch);
+    assert_eq!(kind("1.2.3", "1.2.3^1"), UpdateKind::Patch);
+    assert_eq!(kind("1.2.3^1-5", "1.2.3^2-1"), UpdateKind::Patch);
+    assert_eq!(kind("1.0~rc1", "1.0~rc2"), UpdateKind::PreReleaseOnly);
+    assert_eq!(kind("1.0~rc1", "1.0"), UpdateKind::PreReleaseOnly);
+    assert_eq!(kind("1.0~rc1-1", "1.0-1"), UpdateKind::PreReleaseOnly);
+    assert_eq!(kind("0.9", "1.0~rc1"), UpdateKind::Major);
+    assert_eq!(kind("1.0-1", "1.0-2"), UpdateKind::ReleaseOnly);
+    assert_eq!(kind("1.0", "1.0-1"), UpdateKind::ReleaseOnly);
+    assert_eq!(kind("1.0", "1.00"), UpdateKind::None);
+    assert_eq!(kind("1.0", "1.0"), UpdateKind::None);
+    assert_eq!(kind("2.0", "1.9"), UpdateKind::Downgrade);
+    assert_eq!(kind("1.0", "1.0~rc1"), UpdateKind::Downgrade);
+}
+
+#[test]
+fn comparison_vector() {
+    assert_eq!(
+        Version::from("01.rc").comparison_vector(),
+        [
+            CompareUnit {
+                rank: 6,
+                payload: ComparePayload::Numeric {
+                    len: 1,
+                    digits: "1".into(),
+                },
+            },
+            CompareUnit {
+                rank: 4,
+                payload: ComparePayload::None,
+            },
+            CompareUnit {
+                rank: 5,
+                payload: ComparePayload::Alpha("rc".into()),
+            },
+            CompareUnit {
+                rank: 1,
+                payload: ComparePayload::None,
+            },
+        ]
+    );
+
+    let long = "9".repeat(300);
+    let versions: Vec<Version> = CORPUS
+        .iter()
+        .copied()
+        .chain([long.as_str(), "000", "ab", "abc", "a.b", "1_2"])
+        .map(Version::from)
+        .collect();
+    for a in &versions {
+        let vector = a.comparison_vector();
+        for b in &versions {
+            assert_eq!(vector.cmp(&b.comparison_vector()), a.cmp(b), "{a} vs {b}");
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    {
+        let vector = Version::from("1.0~rc1").comparison_vector();
+        let json = serde_json::to_string(&vector).ok();
+        let parsed = json.and_then(|json| serde_json::from_str::<Vec<CompareUnit>>(&json).ok());
+        assert_eq!(parsed, Some(vector));
+    }
+}